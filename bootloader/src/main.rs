@@ -1,7 +1,7 @@
 //! Bootloader for the zeta project.
 
-#![no_std]
-#![no_main]
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(test), no_main)]
 
 use crate::uefi::{
     tables::system::{Boot, SystemTable},
@@ -16,6 +16,9 @@ mod uefi;
 
 entry_point!(entry_point);
 
+#[cfg(test)]
+fn main() {}
+
 /// The main logic for the bootloader.
 fn entry_point(image_handle: Handle, mut system_table: SystemTable<Boot>) -> Status {
     setup_outputs(&mut system_table);
@@ -90,7 +93,8 @@ fn acquire_boot_directory(image_handle: Handle, mut system_table: &mut SystemTab
 }
 
 /// Handles panics occurring while booting the system.
-#[panic_handler]
+#[cfg_attr(not(test), panic_handler)]
+#[cfg_attr(test, allow(dead_code))]
 fn panic(_info: &core::panic::PanicInfo) -> ! {
     loop {}
 }
@@ -0,0 +1,688 @@
+//! Rust-y wrappers around the simple file system and file protocols.
+
+use core::{marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
+
+use uefi::{
+    datatypes::{CStr16, Guid, Status},
+    protocols::file::{FileAttribute, FileInfo, FileMode, FileProtocol, SimpleFileSystemProtocol},
+};
+
+use crate::uefi::protocols::Protocol;
+
+/// The number of `u64`s in the buffer used by [`File::size()`], which is large enough for a
+/// [`FileInfo`] followed by a 255 character file name.
+const FILE_INFO_BUFFER_LEN: usize = (size_of::<FileInfo>() + 256 * 2).div_ceil(size_of::<u64>());
+
+/// Interface for accessing a file system volume.
+///
+/// A [`SimpleFileSystem`] is obtained through a
+/// [`BootServicesProtocol`](crate::uefi::tables::boot::BootServicesProtocol), and the [`File`]s
+/// opened from it borrow it, so no [`File`] can outlive boot services.
+pub struct SimpleFileSystem {
+    /// Pointer to the interface.
+    ptr: NonNull<SimpleFileSystemProtocol>,
+}
+
+impl SimpleFileSystem {
+    /// Opens the root directory of the volume.
+    ///
+    /// # Errors
+    /// - [`Status::UNSUPPORTED`]
+    ///     - The volume does not support the requested file system type.
+    /// - [`Status::NO_MEDIA`]
+    ///     - The device has no medium.
+    /// - [`Status::DEVICE_ERROR`]
+    ///     - The device reported an error.
+    /// - [`Status::VOLUME_CORRUPTED`]
+    ///     - The file system structures are corrupted.
+    /// - [`Status::ACCESS_DENIED`]
+    ///     - The service denied access to the file.
+    /// - [`Status::OUT_OF_RESOURCES`]
+    ///     - The volume was not opened due to lack of resources.
+    /// - [`Status::MEDIA_CHANGED`]
+    ///     - The device has a different medium in it or the medium is no longer supported.
+    pub fn open_volume(&self) -> Result<File<'_>, Status> {
+        // SAFETY:
+        // All [`SimpleFileSystem`] structures point to a valid [`SimpleFileSystemProtocol`].
+        let open_volume_ptr = unsafe { (*self.ptr.as_ptr()).open_volume };
+
+        let mut root = core::ptr::null_mut();
+
+        // SAFETY:
+        // `open_volume_ptr` is being called with valid arguments and the correct interface.
+        unsafe { open_volume_ptr(self.ptr.as_ptr(), &mut root) }.into_result()?;
+
+        NonNull::new(root)
+            .map(|ptr| File {
+                ptr,
+                lifetime: PhantomData,
+            })
+            .ok_or(Status::DEVICE_ERROR)
+    }
+}
+
+impl Protocol for SimpleFileSystem {
+    const GUID: Guid = SimpleFileSystemProtocol::GUID;
+
+    unsafe fn from_ffi_ptr(ptr: *const core::ffi::c_void) -> Self {
+        SimpleFileSystem {
+            ptr: NonNull::new(ptr.cast::<SimpleFileSystemProtocol>().cast_mut()).unwrap(),
+        }
+    }
+}
+
+/// An open file or directory. The file is closed when this structure is dropped.
+pub struct File<'fs> {
+    /// Pointer to the interface.
+    ptr: NonNull<FileProtocol>,
+    /// Maintains the lifetime of the [`SimpleFileSystem`] the [`File`] was opened from.
+    lifetime: PhantomData<&'fs SimpleFileSystem>,
+}
+
+impl<'fs> File<'fs> {
+    /// Opens the file at `path`, relative to the directory represented by `self`.
+    ///
+    /// # Errors
+    /// - [`Status::NOT_FOUND`]
+    ///     - The file could not be found.
+    /// - [`Status::NO_MEDIA`]
+    ///     - The device has no medium.
+    /// - [`Status::MEDIA_CHANGED`]
+    ///     - The device has a different medium in it or the medium is no longer supported.
+    /// - [`Status::DEVICE_ERROR`]
+    ///     - The device reported an error.
+    /// - [`Status::VOLUME_CORRUPTED`]
+    ///     - The file system structures are corrupted.
+    /// - [`Status::WRITE_PROTECTED`]
+    ///     - An attempt was made to create a file, or open a file for write, on write-protected
+    ///       media.
+    /// - [`Status::ACCESS_DENIED`]
+    ///     - The service denied access to the file.
+    /// - [`Status::OUT_OF_RESOURCES`]
+    ///     - Not enough resources were available to open the file.
+    /// - [`Status::VOLUME_FULL`]
+    ///     - The volume is full.
+    pub fn open(
+        &mut self,
+        path: &CStr16,
+        mode: FileMode,
+        attributes: FileAttribute,
+    ) -> Result<File<'fs>, Status> {
+        // SAFETY:
+        // All [`File`] structures point to a valid [`FileProtocol`].
+        let open_ptr = unsafe { (*self.ptr.as_ptr()).open };
+
+        let mut new_handle = core::ptr::null_mut();
+
+        // SAFETY:
+        // `open_ptr` is being called with valid arguments and the correct interface.
+        unsafe {
+            open_ptr(
+                self.ptr.as_ptr(),
+                &mut new_handle,
+                path.as_ptr(),
+                mode,
+                attributes,
+            )
+        }
+        .into_result()?;
+
+        NonNull::new(new_handle)
+            .map(|ptr| File {
+                ptr,
+                lifetime: PhantomData,
+            })
+            .ok_or(Status::DEVICE_ERROR)
+    }
+
+    /// Reads bytes from the current position of the file into `buffer`, returning the number of
+    /// bytes read.
+    ///
+    /// The firmware may read fewer bytes than fit in `buffer`. A return value of zero indicates
+    /// that the end of the file has been reached.
+    ///
+    /// # Errors
+    /// - [`FileBufferError::BufferTooSmall`]
+    ///     - `self` is a directory and `buffer` is too small to hold the next entry.
+    /// - [`FileBufferError::Status`]
+    ///     - [`Status::NO_MEDIA`]
+    ///         - The device has no medium.
+    ///     - [`Status::DEVICE_ERROR`]
+    ///         - The device reported an error, or the current position is past the end of the
+    ///           file.
+    ///     - [`Status::VOLUME_CORRUPTED`]
+    ///         - The file system structures are corrupted.
+    pub fn read(&mut self, buffer: &mut [u8]) -> Result<usize, FileBufferError> {
+        // SAFETY:
+        // All [`File`] structures point to a valid [`FileProtocol`].
+        let read_ptr = unsafe { (*self.ptr.as_ptr()).read };
+
+        let mut buffer_size = buffer.len();
+
+        // SAFETY:
+        // `read_ptr` is being called with valid arguments and the correct interface.
+        let result = unsafe {
+            read_ptr(
+                self.ptr.as_ptr(),
+                &mut buffer_size,
+                buffer.as_mut_ptr().cast(),
+            )
+        };
+
+        match result {
+            Status::SUCCESS => Ok(buffer_size),
+            Status::BUFFER_TOO_SMALL => Err(FileBufferError::BufferTooSmall {
+                required: buffer_size,
+            }),
+            status => Err(FileBufferError::Status(status)),
+        }
+    }
+
+    /// Reads bytes from the current position of the file until the end of the file is reached,
+    /// returning the number of bytes read into `buffer`.
+    ///
+    /// Partial reads are retried until the firmware reports the end of the file.
+    ///
+    /// # Errors
+    /// - [`FileBufferError::BufferTooSmall`]
+    ///     - The remainder of the file does not fit in `buffer`. `buffer` is filled and the
+    ///       position of the file is left at the first byte that did not fit. `required` is the
+    ///       size of a buffer that would have held everything read by this call.
+    /// - Any error returned by [`File::read()`], [`File::position()`] or [`File::size()`].
+    pub fn read_to_end(&mut self, buffer: &mut [u8]) -> Result<usize, FileBufferError> {
+        let mut filled = 0;
+
+        while filled < buffer.len() {
+            match self.read(&mut buffer[filled..])? {
+                0 => return Ok(filled),
+                read => filled += read,
+            }
+        }
+
+        // `buffer` is full, so check whether the file has anything left without consuming it.
+        let remaining = self.size()?.saturating_sub(self.position()?);
+        if remaining == 0 {
+            Ok(filled)
+        } else {
+            Err(FileBufferError::BufferTooSmall {
+                required: usize::try_from(remaining)
+                    .map_or(usize::MAX, |remaining| filled.saturating_add(remaining)),
+            })
+        }
+    }
+
+    /// Returns the current position of the file.
+    ///
+    /// # Errors
+    /// - [`Status::UNSUPPORTED`]
+    ///     - `self` is a directory.
+    /// - [`Status::DEVICE_ERROR`]
+    ///     - `self` has been deleted.
+    pub fn position(&mut self) -> Result<u64, Status> {
+        // SAFETY:
+        // All [`File`] structures point to a valid [`FileProtocol`].
+        let get_position_ptr = unsafe { (*self.ptr.as_ptr()).get_position };
+
+        let mut position = 0;
+
+        // SAFETY:
+        // `get_position_ptr` is being called with valid arguments and the correct interface.
+        unsafe { get_position_ptr(self.ptr.as_ptr(), &mut position) }.into_result()?;
+
+        Ok(position)
+    }
+
+    /// Sets the current position of the file to `position`.
+    ///
+    /// A `position` of [`u64::MAX`] sets the position to the end of the file.
+    ///
+    /// # Errors
+    /// - [`Status::UNSUPPORTED`]
+    ///     - `self` is a directory and `position` is not zero.
+    /// - [`Status::DEVICE_ERROR`]
+    ///     - `self` has been deleted.
+    pub fn set_position(&mut self, position: u64) -> Result<(), Status> {
+        // SAFETY:
+        // All [`File`] structures point to a valid [`FileProtocol`].
+        let set_position_ptr = unsafe { (*self.ptr.as_ptr()).set_position };
+
+        // SAFETY:
+        // `set_position_ptr` is being called with valid arguments and the correct interface.
+        unsafe { set_position_ptr(self.ptr.as_ptr(), position) }.into_result()
+    }
+
+    /// Writes the [`FileInfo`] of the file into `buffer`, returning a reference to it.
+    ///
+    /// The file name that follows the [`FileInfo`] is also written into `buffer`.
+    ///
+    /// # Errors
+    /// - [`FileBufferError::BufferTooSmall`]
+    ///     - `buffer` is too small to hold the [`FileInfo`] and the file name.
+    /// - [`FileBufferError::Status`]
+    ///     - The firmware reported any other error.
+    pub fn info<'buffer>(
+        &mut self,
+        buffer: &'buffer mut [u64],
+    ) -> Result<&'buffer FileInfo, FileBufferError> {
+        // SAFETY:
+        // All [`File`] structures point to a valid [`FileProtocol`].
+        let get_info_ptr = unsafe { (*self.ptr.as_ptr()).get_info };
+
+        let mut buffer_size = size_of_val(buffer);
+
+        // SAFETY:
+        // `get_info_ptr` is being called with valid arguments and the correct interface.
+        let result = unsafe {
+            get_info_ptr(
+                self.ptr.as_ptr(),
+                &FileInfo::GUID,
+                &mut buffer_size,
+                buffer.as_mut_ptr().cast(),
+            )
+        };
+
+        match result {
+            Status::SUCCESS => {}
+            Status::BUFFER_TOO_SMALL => {
+                return Err(FileBufferError::BufferTooSmall {
+                    required: buffer_size,
+                })
+            }
+            status => return Err(FileBufferError::Status(status)),
+        }
+
+        if buffer_size < size_of::<FileInfo>() {
+            return Err(FileBufferError::Status(Status::DEVICE_ERROR));
+        }
+
+        // SAFETY:
+        // `get_info()` succeeded and reported writing at least a [`FileInfo`] into `buffer`,
+        // which is suitably aligned since it is a slice of `u64`s.
+        Ok(unsafe { &*buffer.as_ptr().cast::<FileInfo>() })
+    }
+
+    /// Returns the size, in bytes, of the file.
+    ///
+    /// # Errors
+    /// - [`Status::BUFFER_TOO_SMALL`]
+    ///     - The name of the file is too long for the [`FileInfo`] to be read.
+    /// - Any error reported by the firmware while reading the [`FileInfo`].
+    pub fn size(&mut self) -> Result<u64, Status> {
+        let mut buffer = [0; FILE_INFO_BUFFER_LEN];
+
+        match self.info(&mut buffer) {
+            Ok(info) => Ok(info.file_size),
+            Err(FileBufferError::BufferTooSmall { .. }) => Err(Status::BUFFER_TOO_SMALL),
+            Err(FileBufferError::Status(status)) => Err(status),
+        }
+    }
+
+    /// Closes the file.
+    ///
+    /// Dropping a [`File`] also closes it, but discards the result.
+    ///
+    /// # Errors
+    /// Returns the [`Status`] reported by the firmware if it is not [`Status::SUCCESS`].
+    pub fn close(self) -> Result<(), Status> {
+        let file = ManuallyDrop::new(self);
+
+        // SAFETY:
+        // All [`File`] structures point to a valid [`FileProtocol`].
+        let close_ptr = unsafe { (*file.ptr.as_ptr()).close };
+
+        // SAFETY:
+        // `close_ptr` is being called with valid arguments and the correct interface, and
+        // `file` is never used again.
+        unsafe { close_ptr(file.ptr.as_ptr()) }.into_result()
+    }
+}
+
+impl Drop for File<'_> {
+    fn drop(&mut self) {
+        // SAFETY:
+        // All [`File`] structures point to a valid [`FileProtocol`].
+        let close_ptr = unsafe { (*self.ptr.as_ptr()).close };
+
+        // SAFETY:
+        // `close_ptr` is being called with valid arguments and the correct interface, and
+        // `self` is never used again.
+        let _ = unsafe { close_ptr(self.ptr.as_ptr()) };
+    }
+}
+
+/// Errors that can occur while reading from a [`File`] into a caller-provided buffer.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum FileBufferError {
+    /// The provided buffer was too small.
+    BufferTooSmall {
+        /// The size, in bytes, of the buffer required.
+        required: usize,
+    },
+    /// The firmware reported an error.
+    Status(Status),
+}
+
+impl From<Status> for FileBufferError {
+    fn from(status: Status) -> Self {
+        FileBufferError::Status(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{ffi::c_void, marker::PhantomData, ptr::NonNull};
+
+    use uefi::{
+        datatypes::{Char16, Guid, RawTime, Status},
+        protocols::file::{FileAttribute, FileInfo, FileMode, FileProtocol},
+    };
+
+    use crate::uefi::protocols::file::{File, FileBufferError};
+
+    /// The contents of every [`FakeFile`].
+    const CONTENTS: &[u8] = b"zeta bootloader";
+
+    /// A [`FileProtocol`] over [`CONTENTS`] that reads at most `chunk` bytes at a time.
+    #[repr(C)]
+    struct FakeFile {
+        /// The interface handed to [`File`].
+        protocol: FileProtocol,
+        /// The current position in [`CONTENTS`].
+        position: usize,
+        /// The maximum number of bytes returned by a single read.
+        chunk: usize,
+        /// Whether [`CONTENTS`] is read as a single directory entry.
+        directory: bool,
+        /// The number of times the file has been closed.
+        closes: usize,
+    }
+
+    impl FakeFile {
+        /// Creates a new [`FakeFile`] that reads at most `chunk` bytes at a time.
+        fn new(chunk: usize) -> FakeFile {
+            FakeFile {
+                protocol: FileProtocol {
+                    revision: FileProtocol::REVISION,
+                    open: unused_open,
+                    close: fake_close,
+                    delete: unused_delete,
+                    read: fake_read,
+                    write: unused_write,
+                    get_position: fake_get_position,
+                    set_position: unused_set_position,
+                    get_info: fake_get_info,
+                    set_info: unused_set_info,
+                    flush: unused_flush,
+                },
+                position: 0,
+                chunk,
+                directory: false,
+                closes: 0,
+            }
+        }
+
+        /// Creates a new [`FakeFile`] that reads [`CONTENTS`] as a single directory entry.
+        fn directory() -> FakeFile {
+            FakeFile {
+                directory: true,
+                ..FakeFile::new(CONTENTS.len())
+            }
+        }
+
+        /// Opens `self` as a [`File`].
+        fn open(&mut self) -> File<'_> {
+            // The pointer covers the whole [`FakeFile`], so the fake services may access it.
+            File {
+                ptr: NonNull::from(self).cast::<FileProtocol>(),
+                lifetime: PhantomData,
+            }
+        }
+    }
+
+    /// Reads up to [`FakeFile::chunk`] bytes of [`CONTENTS`].
+    unsafe extern "efiapi" fn fake_read(
+        this: *mut FileProtocol,
+        buffer_size: *mut usize,
+        buffer: *mut c_void,
+    ) -> Status {
+        // SAFETY:
+        // Every [`FileProtocol`] in these tests is the first field of a [`FakeFile`].
+        let file = unsafe { &mut *this.cast::<FakeFile>() };
+        // SAFETY:
+        // `File::read()` passes a valid buffer size.
+        let size = unsafe { &mut *buffer_size };
+
+        if file.directory && *size < CONTENTS.len() - file.position {
+            *size = CONTENTS.len() - file.position;
+            return Status::BUFFER_TOO_SMALL;
+        }
+
+        let read = (*size).min(file.chunk).min(CONTENTS.len() - file.position);
+        // SAFETY:
+        // `File::read()` passes a buffer of at least `size` bytes.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                CONTENTS[file.position..].as_ptr(),
+                buffer.cast::<u8>(),
+                read,
+            )
+        };
+
+        file.position += read;
+        *size = read;
+        Status::SUCCESS
+    }
+
+    /// Writes a [`FileInfo`] for [`CONTENTS`] followed by a one character name.
+    unsafe extern "efiapi" fn fake_get_info(
+        _this: *mut FileProtocol,
+        information_type: *const Guid,
+        buffer_size: *mut usize,
+        buffer: *mut c_void,
+    ) -> Status {
+        // SAFETY:
+        // `File::info()` passes a valid [`Guid`].
+        assert_eq!(unsafe { (*information_type).data1 }, FileInfo::GUID.data1);
+
+        let required = size_of::<FileInfo>() + 2 * size_of::<Char16>();
+        // SAFETY:
+        // `File::info()` passes a valid buffer size.
+        let size = unsafe { &mut *buffer_size };
+        if *size < required {
+            *size = required;
+            return Status::BUFFER_TOO_SMALL;
+        }
+
+        let info = FileInfo {
+            size: required as u64,
+            file_size: CONTENTS.len() as u64,
+            physical_size: 512,
+            create_time: RawTime::default(),
+            last_access_time: RawTime::default(),
+            modification_time: RawTime::default(),
+            attribute: FileAttribute::READ_ONLY,
+        };
+        // SAFETY:
+        // `File::info()` passes a suitably aligned buffer of at least `required` bytes.
+        unsafe { buffer.cast::<FileInfo>().write(info) };
+
+        *size = required;
+        Status::SUCCESS
+    }
+
+    /// Reports the current position in [`CONTENTS`].
+    unsafe extern "efiapi" fn fake_get_position(
+        this: *mut FileProtocol,
+        position: *mut u64,
+    ) -> Status {
+        // SAFETY:
+        // Every [`FileProtocol`] in these tests is the first field of a [`FakeFile`].
+        let file = unsafe { &*this.cast::<FakeFile>() };
+
+        // SAFETY:
+        // `File::position()` passes a valid location for the position.
+        unsafe { position.write(file.position as u64) };
+
+        Status::SUCCESS
+    }
+
+    /// Records that the file was closed.
+    unsafe extern "efiapi" fn fake_close(this: *mut FileProtocol) -> Status {
+        // SAFETY:
+        // Every [`FileProtocol`] in these tests is the first field of a [`FakeFile`].
+        unsafe { (*this.cast::<FakeFile>()).closes += 1 };
+
+        Status::SUCCESS
+    }
+
+    /// Stands in for [`FileProtocol::open`].
+    unsafe extern "efiapi" fn unused_open(
+        _: *mut FileProtocol,
+        _: *mut *mut FileProtocol,
+        _: *const Char16,
+        _: FileMode,
+        _: FileAttribute,
+    ) -> Status {
+        unreachable!("unused file service called")
+    }
+
+    /// Stands in for [`FileProtocol::delete`].
+    unsafe extern "efiapi" fn unused_delete(_: *mut FileProtocol) -> Status {
+        unreachable!("unused file service called")
+    }
+
+    /// Stands in for [`FileProtocol::flush`].
+    unsafe extern "efiapi" fn unused_flush(_: *mut FileProtocol) -> Status {
+        unreachable!("unused file service called")
+    }
+
+    /// Stands in for [`FileProtocol::write`].
+    unsafe extern "efiapi" fn unused_write(
+        _: *mut FileProtocol,
+        _: *mut usize,
+        _: *const c_void,
+    ) -> Status {
+        unreachable!("unused file service called")
+    }
+
+    /// Stands in for [`FileProtocol::set_position`].
+    unsafe extern "efiapi" fn unused_set_position(_: *mut FileProtocol, _: u64) -> Status {
+        unreachable!("unused file service called")
+    }
+
+    /// Stands in for [`FileProtocol::set_info`].
+    unsafe extern "efiapi" fn unused_set_info(
+        _: *mut FileProtocol,
+        _: *const Guid,
+        _: usize,
+        _: *const c_void,
+    ) -> Status {
+        unreachable!("unused file service called")
+    }
+
+    #[test]
+    fn read_to_end_assembles_chunked_reads() {
+        let mut fake = FakeFile::new(4);
+        let mut buffer = [0; 32];
+
+        let read = fake.open().read_to_end(&mut buffer).unwrap();
+        assert_eq!(&buffer[..read], CONTENTS);
+        assert_eq!(fake.closes, 1);
+    }
+
+    #[test]
+    fn read_to_end_exactly_full_buffer() {
+        let mut fake = FakeFile::new(4);
+        let mut buffer = [0; CONTENTS.len()];
+
+        assert_eq!(fake.open().read_to_end(&mut buffer), Ok(CONTENTS.len()));
+        assert_eq!(buffer, CONTENTS);
+    }
+
+    #[test]
+    fn read_to_end_buffer_too_small() {
+        let mut fake = FakeFile::new(4);
+        let mut buffer = [0; CONTENTS.len() - 1];
+
+        assert_eq!(
+            fake.open().read_to_end(&mut buffer),
+            Err(FileBufferError::BufferTooSmall {
+                required: CONTENTS.len()
+            })
+        );
+        assert_eq!(buffer, CONTENTS[..buffer.len()]);
+    }
+
+    #[test]
+    fn read_to_end_buffer_too_small_keeps_remaining_bytes() {
+        let mut fake = FakeFile::new(4);
+        let mut file = fake.open();
+        let mut buffer = [0; CONTENTS.len() - 1];
+
+        assert_eq!(
+            file.read_to_end(&mut buffer),
+            Err(FileBufferError::BufferTooSmall {
+                required: CONTENTS.len()
+            })
+        );
+
+        let mut next = [0; 4];
+        assert_eq!(file.read(&mut next), Ok(1));
+        assert_eq!(next[0], CONTENTS[CONTENTS.len() - 1]);
+    }
+
+    #[test]
+    fn read_to_end_empty_buffer_consumes_nothing() {
+        let mut fake = FakeFile::new(4);
+        let mut file = fake.open();
+
+        assert_eq!(
+            file.read_to_end(&mut []),
+            Err(FileBufferError::BufferTooSmall {
+                required: CONTENTS.len()
+            })
+        );
+
+        let mut next = [0; 1];
+        assert_eq!(file.read(&mut next), Ok(1));
+        assert_eq!(next[0], CONTENTS[0]);
+    }
+
+    #[test]
+    fn info_buffer_too_small_reports_required_size() {
+        let mut fake = FakeFile::new(4);
+        let mut buffer = [0; 2];
+
+        assert_eq!(
+            fake.open().info(&mut buffer).err(),
+            Some(FileBufferError::BufferTooSmall {
+                required: size_of::<FileInfo>() + 2 * size_of::<Char16>(),
+            })
+        );
+    }
+
+    #[test]
+    fn size_reads_file_info() {
+        let mut fake = FakeFile::new(4);
+
+        assert_eq!(fake.open().size(), Ok(CONTENTS.len() as u64));
+    }
+
+    #[test]
+    fn read_directory_entry_buffer_too_small_reports_required_size() {
+        let mut fake = FakeFile::directory();
+        let mut file = fake.open();
+        let mut buffer = [0; 4];
+
+        assert_eq!(
+            file.read(&mut buffer),
+            Err(FileBufferError::BufferTooSmall {
+                required: CONTENTS.len()
+            })
+        );
+
+        let mut buffer = [0; CONTENTS.len()];
+        assert_eq!(file.read(&mut buffer), Ok(CONTENTS.len()));
+        assert_eq!(buffer, CONTENTS);
+    }
+}
@@ -0,0 +1,148 @@
+//! Rust-y wrapper around the loaded image protocol.
+
+use core::ptr::NonNull;
+
+use uefi::{
+    datatypes::{Guid, Handle},
+    protocols::{device_path::DevicePathProtocol, loaded_image::LoadedImageProtocol},
+};
+
+use crate::uefi::protocols::Protocol;
+
+/// Information about a loaded image.
+pub struct LoadedImage {
+    /// Pointer to the interface.
+    ptr: NonNull<LoadedImageProtocol>,
+}
+
+impl LoadedImage {
+    /// Returns the handle of the device from which the image was loaded, or [`None`] if the
+    /// image was not loaded from a device.
+    pub fn device_handle(&self) -> Option<Handle> {
+        // SAFETY:
+        // All [`LoadedImage`] structures point to a valid [`LoadedImageProtocol`].
+        Handle::new(unsafe { (*self.ptr.as_ptr()).device_handle })
+    }
+
+    /// Returns the file path of the image, relative to [`LoadedImage::device_handle()`], or
+    /// [`None`] if the image was not loaded from a file.
+    pub fn file_path(&self) -> Option<NonNull<DevicePathProtocol>> {
+        // SAFETY:
+        // All [`LoadedImage`] structures point to a valid [`LoadedImageProtocol`].
+        NonNull::new(unsafe { (*self.ptr.as_ptr()).file_path })
+    }
+
+    /// Returns the binary load options that were passed to the image.
+    pub fn load_options(&self) -> &[u8] {
+        // SAFETY:
+        // All [`LoadedImage`] structures point to a valid [`LoadedImageProtocol`].
+        let protocol = unsafe { &*self.ptr.as_ptr() };
+
+        if protocol.load_options.is_null() {
+            return &[];
+        }
+
+        // SAFETY:
+        // The firmware guarantees that `load_options` points to `load_options_size` readable bytes.
+        unsafe {
+            core::slice::from_raw_parts(
+                protocol.load_options.cast::<u8>(),
+                protocol.load_options_size as usize,
+            )
+        }
+    }
+}
+
+impl Protocol for LoadedImage {
+    const GUID: Guid = LoadedImageProtocol::GUID;
+
+    unsafe fn from_ffi_ptr(ptr: *const core::ffi::c_void) -> Self {
+        LoadedImage {
+            ptr: NonNull::new(ptr.cast::<LoadedImageProtocol>().cast_mut()).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{ffi::c_void, ptr::NonNull};
+
+    use uefi::{
+        datatypes::RawHandle,
+        protocols::{device_path::DevicePathProtocol, loaded_image::LoadedImageProtocol},
+        tables::boot_services::MemoryType,
+    };
+
+    use crate::uefi::protocols::{loaded_image::LoadedImage, Protocol};
+
+    /// Creates a [`LoadedImageProtocol`] loaded from `device_handle` and `file_path` with
+    /// `load_options`.
+    fn fake_protocol(
+        device_handle: RawHandle,
+        file_path: *mut DevicePathProtocol,
+        load_options: Option<&mut [u8]>,
+    ) -> LoadedImageProtocol {
+        let (load_options_size, load_options) = match load_options {
+            Some(options) => (options.len() as u32, options.as_mut_ptr().cast()),
+            None => (0, core::ptr::null_mut()),
+        };
+
+        LoadedImageProtocol {
+            revision: LoadedImageProtocol::REVISION,
+            parent_handle: RawHandle::null(),
+            system_table: core::ptr::null_mut(),
+            device_handle,
+            file_path,
+            _reserved: core::ptr::null_mut(),
+            load_options_size,
+            load_options,
+            image_base: core::ptr::null_mut(),
+            image_size: 0,
+            image_code_type: MemoryType::LOADER_CODE,
+            image_data_type: MemoryType::LOADER_DATA,
+            unload: None,
+        }
+    }
+
+    /// Wraps `protocol` in a [`LoadedImage`].
+    fn wrap(protocol: &LoadedImageProtocol) -> LoadedImage {
+        // SAFETY:
+        // `protocol` points to a valid [`LoadedImageProtocol`].
+        unsafe { LoadedImage::from_ffi_ptr(core::ptr::from_ref(protocol).cast::<c_void>()) }
+    }
+
+    #[test]
+    fn reads_fields() {
+        let mut device = 0u8;
+        let mut path = DevicePathProtocol {
+            kind: 0x7F,
+            sub_kind: 0xFF,
+            length: [4, 0],
+        };
+        let mut options = *b"kernel.elf";
+
+        let protocol = fake_protocol(
+            RawHandle(core::ptr::from_mut(&mut device).cast()),
+            &mut path,
+            Some(&mut options),
+        );
+        let image = wrap(&protocol);
+
+        assert_eq!(
+            image.device_handle().map(|handle| handle.as_ptr()),
+            Some(core::ptr::from_mut(&mut device).cast())
+        );
+        assert_eq!(image.file_path(), Some(NonNull::from(&mut path)));
+        assert_eq!(image.load_options(), b"kernel.elf");
+    }
+
+    #[test]
+    fn null_device_handle_and_load_options() {
+        let protocol = fake_protocol(RawHandle::null(), core::ptr::null_mut(), None);
+        let image = wrap(&protocol);
+
+        assert!(image.device_handle().is_none());
+        assert!(image.file_path().is_none());
+        assert!(image.load_options().is_empty());
+    }
+}
@@ -3,6 +3,8 @@
 use uefi::datatypes::Guid;
 
 pub mod console;
+pub mod file;
+pub mod loaded_image;
 
 /// A UEFI protocol.
 pub trait Protocol {
@@ -2,7 +2,12 @@
 
 use core::{marker::PhantomData, ptr::NonNull, sync::atomic::AtomicUsize};
 
-use uefi::{datatypes::Status, tables::boot_services::RawBootServicesTable};
+use uefi::{
+    datatypes::{Handle, Status},
+    tables::boot_services::RawBootServicesTable,
+};
+
+use crate::uefi::protocols::Protocol;
 
 /// The number of active [`BootHandle`]s.
 static BOOT_HANDLE_COUNT: AtomicUsize = AtomicUsize::new(0);
@@ -18,7 +23,7 @@ pub struct BootServicesTable<'table> {
     pub(in crate::uefi::tables) lifetime: PhantomData<&'table mut RawBootServicesTable>,
 }
 
-impl BootServicesTable<'_> {
+impl<'table> BootServicesTable<'table> {
     /// Stalls the processor.
     ///
     /// Stalls execution on the processor for at least `microseconds` microseconds.
@@ -35,4 +40,351 @@ impl BootServicesTable<'_> {
         // According to the UEFI specification, `stall()` may only return [`Status::SUCCESS`].
         assert_eq!(result, Status::SUCCESS);
     }
+
+    /// Returns the interface of the [`Protocol`] `P` installed on `handle`.
+    ///
+    /// # Errors
+    /// - [`Status::UNSUPPORTED`]
+    ///     - `handle` does not support `P`.
+    /// - [`Status::INVALID_PARAMETER`]
+    ///     - The firmware rejected the request.
+    pub fn handle_protocol<P: Protocol>(
+        &self,
+        handle: Handle,
+    ) -> Result<BootServicesProtocol<'table, P>, Status> {
+        // SAFETY:
+        // `self.ptr` points to a readable [`RawBootServicesTable`].
+        let handle_protocol_ptr = unsafe { (*self.ptr.as_ptr()).handle_protocol };
+
+        let mut interface = core::ptr::null_mut();
+
+        // SAFETY:
+        // `handle_protocol()` was passed valid arguments.
+        unsafe { handle_protocol_ptr(handle, &P::GUID, &mut interface) }.into_result()?;
+
+        if interface.is_null() {
+            return Err(Status::UNSUPPORTED);
+        }
+
+        // SAFETY:
+        // `handle_protocol()` succeeded, so `interface` points to a valid instance of `P`.
+        let protocol = unsafe { P::from_ffi_ptr(interface) };
+
+        Ok(BootServicesProtocol {
+            protocol,
+            lifetime: PhantomData,
+        })
+    }
+
+    /// Returns the first interface in the handle database that supports the [`Protocol`] `P`.
+    ///
+    /// # Errors
+    /// - [`Status::INVALID_PARAMETER`]
+    ///     - The firmware rejected the request.
+    /// - [`Status::NOT_FOUND`]
+    ///     - No interface supporting `P` is installed, or the firmware returned a null interface.
+    pub fn locate_protocol<P: Protocol>(&self) -> Result<BootServicesProtocol<'table, P>, Status> {
+        // SAFETY:
+        // `self.ptr` points to a readable [`RawBootServicesTable`].
+        let locate_protocol_ptr = unsafe { (*self.ptr.as_ptr()).locate_protocol };
+
+        let mut interface = core::ptr::null_mut();
+
+        // SAFETY:
+        // `locate_protocol()` was passed valid arguments.
+        unsafe { locate_protocol_ptr(&P::GUID, core::ptr::null_mut(), &mut interface) }
+            .into_result()?;
+
+        if interface.is_null() {
+            return Err(Status::NOT_FOUND);
+        }
+
+        // SAFETY:
+        // `locate_protocol()` succeeded, so `interface` points to a valid instance of `P`.
+        let protocol = unsafe { P::from_ffi_ptr(interface) };
+
+        Ok(BootServicesProtocol {
+            protocol,
+            lifetime: PhantomData,
+        })
+    }
+}
+
+/// [`Protocol`] interfaces obtained through a [`BootServicesTable`].
+///
+/// The interface cannot outlive the [`BootServicesTable`] it was obtained from, and so cannot be
+/// used after boot services have been exited.
+pub struct BootServicesProtocol<'table, P: Protocol> {
+    /// The [`Protocol`] struct contains.
+    protocol: P,
+    /// Maintains the proper lifetime from the [`BootServicesTable`].
+    lifetime: PhantomData<&'table P>,
+}
+
+impl<P: Protocol> core::ops::Deref for BootServicesProtocol<'_, P> {
+    type Target = P;
+
+    fn deref(&self) -> &Self::Target {
+        &self.protocol
+    }
+}
+
+impl<P: Protocol> core::ops::DerefMut for BootServicesProtocol<'_, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.protocol
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{ffi::c_void, marker::PhantomData, ptr::NonNull};
+
+    use uefi::{
+        datatypes::{Guid, Handle, RawHandle, Status},
+        tables::{boot_services::RawBootServicesTable, Header},
+    };
+
+    use crate::uefi::{protocols::Protocol, tables::boot::BootServicesTable};
+
+    /// The interface returned by [`locate_found`].
+    static INTERFACE: u64 = 0x1234_5678;
+
+    /// A [`Protocol`] that records the interface it was created from.
+    struct FakeProtocol(*const c_void);
+
+    impl Protocol for FakeProtocol {
+        const GUID: Guid = Guid {
+            data1: 0xDEADBEEF,
+            data2: 0x0123,
+            data3: 0x4567,
+            data4: [0; 8],
+        };
+
+        unsafe fn from_ffi_ptr(ptr: *const c_void) -> Self {
+            FakeProtocol(ptr)
+        }
+    }
+
+    /// Stands in for every boot service the tests do not call.
+    unsafe extern "efiapi" fn unused_service() {
+        unreachable!("unused boot service called")
+    }
+
+    /// Stands in for [`RawBootServicesTable::stall`].
+    unsafe extern "efiapi" fn unused_stall(_: usize) -> Status {
+        unreachable!("unused boot service called")
+    }
+
+    /// Stands in for [`RawBootServicesTable::open_protocol`].
+    unsafe extern "efiapi" fn unused_open_protocol(
+        _: Handle,
+        _: *mut Guid,
+        _: *mut *mut c_void,
+        _: Handle,
+        _: Option<Handle>,
+    ) {
+        unreachable!("unused boot service called")
+    }
+
+    /// Stands in for [`RawBootServicesTable::handle_protocol`].
+    unsafe extern "efiapi" fn unused_handle_protocol(
+        _: Handle,
+        _: *const Guid,
+        _: *mut *mut c_void,
+    ) -> Status {
+        unreachable!("unused boot service called")
+    }
+
+    /// Stands in for [`RawBootServicesTable::locate_protocol`].
+    unsafe extern "efiapi" fn unused_locate_protocol(
+        _: *const Guid,
+        _: *mut c_void,
+        _: *mut *mut c_void,
+    ) -> Status {
+        unreachable!("unused boot service called")
+    }
+
+    /// Provides [`INTERFACE`] if asked for [`FakeProtocol`] on any handle.
+    unsafe extern "efiapi" fn handle_found(
+        _handle: Handle,
+        protocol: *const Guid,
+        interface: *mut *mut c_void,
+    ) -> Status {
+        // SAFETY:
+        // `handle_protocol()` passes a valid [`Guid`].
+        if unsafe { (*protocol).data1 } != FakeProtocol::GUID.data1 {
+            return Status::UNSUPPORTED;
+        }
+
+        // SAFETY:
+        // `handle_protocol()` passes a valid location for the interface.
+        unsafe { interface.write(core::ptr::from_ref(&INTERFACE).cast_mut().cast()) };
+
+        Status::SUCCESS
+    }
+
+    /// Reports success without providing an interface.
+    unsafe extern "efiapi" fn handle_null(
+        _handle: Handle,
+        _protocol: *const Guid,
+        interface: *mut *mut c_void,
+    ) -> Status {
+        // SAFETY:
+        // `handle_protocol()` passes a valid location for the interface.
+        unsafe { interface.write(core::ptr::null_mut()) };
+
+        Status::SUCCESS
+    }
+
+    /// Locates [`INTERFACE`] if asked for [`FakeProtocol`].
+    unsafe extern "efiapi" fn locate_found(
+        protocol: *const Guid,
+        _registration: *mut c_void,
+        interface: *mut *mut c_void,
+    ) -> Status {
+        // SAFETY:
+        // `locate_protocol()` passes a valid [`Guid`].
+        if unsafe { (*protocol).data1 } != FakeProtocol::GUID.data1 {
+            return Status::NOT_FOUND;
+        }
+
+        // SAFETY:
+        // `locate_protocol()` passes a valid location for the interface.
+        unsafe { interface.write(core::ptr::from_ref(&INTERFACE).cast_mut().cast()) };
+
+        Status::SUCCESS
+    }
+
+    /// Reports success without providing an interface.
+    unsafe extern "efiapi" fn locate_null(
+        _protocol: *const Guid,
+        _registration: *mut c_void,
+        interface: *mut *mut c_void,
+    ) -> Status {
+        // SAFETY:
+        // `locate_protocol()` passes a valid location for the interface.
+        unsafe { interface.write(core::ptr::null_mut()) };
+
+        Status::SUCCESS
+    }
+
+    /// Creates a [`RawBootServicesTable`] whose protocol services are `handle_protocol` and
+    /// `locate_protocol`.
+    fn fake_table(
+        handle_protocol: unsafe extern "efiapi" fn(Handle, *const Guid, *mut *mut c_void) -> Status,
+        locate_protocol: unsafe extern "efiapi" fn(
+            *const Guid,
+            *mut c_void,
+            *mut *mut c_void,
+        ) -> Status,
+    ) -> RawBootServicesTable {
+        RawBootServicesTable {
+            header: Header {
+                signature: RawBootServicesTable::SIGNATURE,
+                revision: 0,
+                size: size_of::<RawBootServicesTable>() as u32,
+                crc32: 0,
+                _reserved: 0,
+            },
+            raise_tpl: unused_service,
+            restore_tpl: unused_service,
+            allocate_pages: unused_service,
+            free_pages: unused_service,
+            get_memory_map: unused_service,
+            allocate_pool: unused_service,
+            free_pool: unused_service,
+            create_event: unused_service,
+            set_timer: unused_service,
+            wait_for_event: unused_service,
+            signal_event: unused_service,
+            close_event: unused_service,
+            check_event: unused_service,
+            install_protocol_interface: unused_service,
+            reinstall_protocol_interface: unused_service,
+            uninstall_protocol_interface: unused_service,
+            handle_protocol,
+            _reserved: core::ptr::null_mut(),
+            register_protocol_notify: unused_service,
+            locate_handle: unused_service,
+            locate_device_path: unused_service,
+            install_configuration_table: unused_service,
+            load_image: unused_service,
+            start_image: unused_service,
+            exit: unused_service,
+            unload_image: unused_service,
+            exit_boot_services: unused_service,
+            get_next_monotonic_count: unused_service,
+            stall: unused_stall,
+            set_watchdog_timer: unused_service,
+            connect_controller: unused_service,
+            disconnect_controller: unused_service,
+            open_protocol: unused_open_protocol,
+            close_protocol: unused_service,
+            open_protocol_information: unused_service,
+            protocols_per_handle: unused_service,
+            locate_handle_buffer: unused_service,
+            locate_protocol,
+            install_multiple_protocol_interface: unused_service,
+            uninstall_multiple_protocol_interface: unused_service,
+            calculate_crc32: unused_service,
+            copy_mem: unused_service,
+            set_mem: unused_service,
+            create_event_ex: unused_service,
+        }
+    }
+
+    /// Wraps `raw` in a [`BootServicesTable`].
+    fn wrap(raw: &mut RawBootServicesTable) -> BootServicesTable<'_> {
+        BootServicesTable {
+            ptr: NonNull::from(raw),
+            lifetime: PhantomData,
+        }
+    }
+
+    #[test]
+    fn locate_protocol_found() {
+        let mut raw = fake_table(unused_handle_protocol, locate_found);
+        let table = wrap(&mut raw);
+
+        let protocol = table.locate_protocol::<FakeProtocol>().unwrap();
+        assert_eq!(protocol.0, core::ptr::from_ref(&INTERFACE).cast());
+    }
+
+    #[test]
+    fn locate_protocol_null_interface() {
+        let mut raw = fake_table(unused_handle_protocol, locate_null);
+        let table = wrap(&mut raw);
+
+        assert!(matches!(
+            table.locate_protocol::<FakeProtocol>(),
+            Err(Status::NOT_FOUND)
+        ));
+    }
+
+    /// Returns a non-null [`Handle`] for use with the fake services.
+    fn fake_handle() -> Handle {
+        Handle::new(RawHandle(core::ptr::from_ref(&INTERFACE).cast_mut().cast())).unwrap()
+    }
+
+    #[test]
+    fn handle_protocol_found() {
+        let mut raw = fake_table(handle_found, unused_locate_protocol);
+        let table = wrap(&mut raw);
+
+        let protocol = table
+            .handle_protocol::<FakeProtocol>(fake_handle())
+            .unwrap();
+        assert_eq!(protocol.0, core::ptr::from_ref(&INTERFACE).cast());
+    }
+
+    #[test]
+    fn handle_protocol_null_interface() {
+        let mut raw = fake_table(handle_null, unused_locate_protocol);
+        let table = wrap(&mut raw);
+
+        assert!(matches!(
+            table.handle_protocol::<FakeProtocol>(fake_handle()),
+            Err(Status::UNSUPPORTED)
+        ));
+    }
 }
@@ -2,7 +2,7 @@
 
 use core::{fmt::Write, marker::PhantomData};
 
-use crate::logging::Logger;
+use crate::logging::{self, LevelTag, Logger};
 
 pub mod boot;
 
@@ -61,6 +61,11 @@ impl Write for DebugConLogger {
 
 impl Logger for DebugConLogger {
     fn log(&mut self, level: crate::logging::LogLevel, args: core::fmt::Arguments) {
-        let _ = write!(self, "[{:?}]: {}", level, args);
+        let _ = write!(
+            self,
+            "{}: {}",
+            LevelTag::new(level, logging::color_enabled()),
+            args
+        );
     }
 }
@@ -0,0 +1,149 @@
+//! Atomic wrappers around types that are not natively atomic.
+
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// An enum that can be losslessly converted to and from a [`u8`].
+pub trait U8Enum: Copy {
+    /// Converts `self` into its [`u8`] representation.
+    fn into_u8(self) -> u8;
+
+    /// Converts `value` back into `Self`, returning [`None`] if `value` does not correspond to
+    /// any variant.
+    fn from_u8(value: u8) -> Option<Self>;
+}
+
+/// An enum which can be safely shared between contexts, backed by an [`AtomicU8`].
+pub struct AtomicEnum<E: U8Enum> {
+    /// The [`u8`] representation of the stored value.
+    value: AtomicU8,
+    /// The type of the stored value.
+    phantom: PhantomData<E>,
+}
+
+impl<E: U8Enum> AtomicEnum<E> {
+    /// Creates a new [`AtomicEnum`] storing `value`.
+    pub fn new(value: E) -> AtomicEnum<E> {
+        AtomicEnum::from_raw(value.into_u8())
+    }
+
+    /// Creates a new [`AtomicEnum`] from the [`u8`] representation of a variant of `E`.
+    ///
+    /// This exists so that [`AtomicEnum`]s can be created in `const` contexts. If `raw` does not
+    /// correspond to any variant of `E`, then [`AtomicEnum::load()`] will panic.
+    pub const fn from_raw(raw: u8) -> AtomicEnum<E> {
+        AtomicEnum {
+            value: AtomicU8::new(raw),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Loads the stored value.
+    ///
+    /// # Panics
+    /// If the stored value does not correspond to a variant of `E`, which is only possible if the
+    /// [`AtomicEnum`] was created by [`AtomicEnum::from_raw()`] with an invalid value.
+    pub fn load(&self, ordering: Ordering) -> E {
+        E::from_u8(self.value.load(ordering)).expect("invalid AtomicEnum value")
+    }
+
+    /// Stores `value`.
+    pub fn store(&self, value: E, ordering: Ordering) {
+        self.value.store(value.into_u8(), ordering);
+    }
+
+    /// Stores `new` if the stored value is the same as `current`.
+    ///
+    /// # Errors
+    /// If the stored value was not `current`, the stored value is returned in [`Err`].
+    ///
+    /// # Panics
+    /// If the stored value does not correspond to a variant of `E`, which is only possible if the
+    /// [`AtomicEnum`] was created by [`AtomicEnum::from_raw()`] with an invalid value.
+    pub fn compare_exchange(
+        &self,
+        current: E,
+        new: E,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<E, E> {
+        self.value
+            .compare_exchange(current.into_u8(), new.into_u8(), success, failure)
+            .map(|value| E::from_u8(value).expect("invalid AtomicEnum value"))
+            .map_err(|value| E::from_u8(value).expect("invalid AtomicEnum value"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::Ordering;
+
+    use super::{AtomicEnum, U8Enum};
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[repr(u8)]
+    enum State {
+        Idle = 0,
+        Running = 1,
+        Done = 2,
+    }
+
+    impl U8Enum for State {
+        fn into_u8(self) -> u8 {
+            self as u8
+        }
+
+        fn from_u8(value: u8) -> Option<Self> {
+            match value {
+                0 => Some(State::Idle),
+                1 => Some(State::Running),
+                2 => Some(State::Done),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let state = AtomicEnum::new(State::Idle);
+        assert_eq!(state.load(Ordering::Relaxed), State::Idle);
+
+        for value in [State::Running, State::Done, State::Idle] {
+            state.store(value, Ordering::Relaxed);
+            assert_eq!(state.load(Ordering::Relaxed), value);
+        }
+    }
+
+    #[test]
+    fn compare_exchange() {
+        let state = AtomicEnum::new(State::Idle);
+
+        assert_eq!(
+            state.compare_exchange(
+                State::Idle,
+                State::Running,
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            ),
+            Ok(State::Idle)
+        );
+        assert_eq!(
+            state.compare_exchange(
+                State::Idle,
+                State::Done,
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            ),
+            Err(State::Running)
+        );
+        assert_eq!(state.load(Ordering::Relaxed), State::Running);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid AtomicEnum value")]
+    fn invalid_raw_value() {
+        AtomicEnum::<State>::from_raw(3).load(Ordering::Relaxed);
+    }
+}
@@ -1,6 +1,11 @@
 //! Code for controlled modifications, placing all unsafety on the initialization function.
 
-use core::cell::UnsafeCell;
+use core::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::atomic::{AtomicU8, Ordering},
+};
 
 /// Wrapper struct for variables that are modified by the loading process and never touched again.
 pub struct ControllledModificationCell<T> {
@@ -52,3 +57,324 @@ impl<T: Copy> ControllledModificationCell<T> {
         unsafe { *self.value.get() }
     }
 }
+
+/// The [`OnceLock`] has not been initialized.
+const ONCE_UNINIT: u8 = 0;
+/// The [`OnceLock`] is currently being initialized.
+const ONCE_RUNNING: u8 = 1;
+/// The [`OnceLock`] has been initialized.
+const ONCE_COMPLETE: u8 = 2;
+
+/// A cell which can be written to only once.
+///
+/// Unlike [`Lazy`], the value of a [`OnceLock`] is provided by whichever context first
+/// initializes it, rather than at construction.
+pub struct OnceLock<T> {
+    /// The initialization state of the [`OnceLock`].
+    state: AtomicU8,
+    /// The value, valid once `state` is [`ONCE_COMPLETE`].
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY:
+// `value` is only written by the single context that moves `state` out of [`ONCE_UNINIT`], and is
+// only read after that write has been published with [`Ordering::Release`], so sharing a
+// [`OnceLock`] shares `&T` between contexts and may move a `T` into it from any context.
+unsafe impl<T: Send + Sync> Sync for OnceLock<T> {}
+
+impl<T> OnceLock<T> {
+    /// Creates a new uninitialized [`OnceLock`].
+    pub const fn new() -> OnceLock<T> {
+        OnceLock {
+            state: AtomicU8::new(ONCE_UNINIT),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns a reference to the value, or [`None`] if the [`OnceLock`] has not been
+    /// initialized.
+    ///
+    /// This does not wait for an in-progress initialization to complete.
+    pub fn get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) == ONCE_COMPLETE {
+            // SAFETY:
+            // `state` is [`ONCE_COMPLETE`], so `value` has been initialized and is never
+            // mutated again.
+            Some(unsafe { self.get_unchecked() })
+        } else {
+            None
+        }
+    }
+
+    /// Initializes the [`OnceLock`] with `value`.
+    ///
+    /// # Errors
+    /// If the [`OnceLock`] has already been initialized, or is being initialized, `value` is
+    /// returned in [`Err`].
+    pub fn set(&self, value: T) -> Result<(), T> {
+        if self
+            .state
+            .compare_exchange(
+                ONCE_UNINIT,
+                ONCE_RUNNING,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .is_err()
+        {
+            return Err(value);
+        }
+
+        // SAFETY:
+        // This context moved `state` out of [`ONCE_UNINIT`], so it has exclusive access to
+        // `value`.
+        unsafe { (*self.value.get()).write(value) };
+        self.state.store(ONCE_COMPLETE, Ordering::Release);
+
+        Ok(())
+    }
+
+    /// Returns a reference to the value, initializing it with `f` if the [`OnceLock`] has not
+    /// been initialized.
+    ///
+    /// If another context is initializing the [`OnceLock`], this spins until it is done. If `f`
+    /// panics, the panic is propagated and the [`OnceLock`] remains uninitialized.
+    pub fn get_or_init<F: FnOnce() -> T>(&self, f: F) -> &T {
+        loop {
+            match self.state.compare_exchange(
+                ONCE_UNINIT,
+                ONCE_RUNNING,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(ONCE_COMPLETE) => {
+                    // SAFETY:
+                    // `state` is [`ONCE_COMPLETE`], so `value` has been initialized and is never
+                    // mutated again.
+                    return unsafe { self.get_unchecked() };
+                }
+                Err(_) => core::hint::spin_loop(),
+            }
+        }
+
+        /// Resets the [`OnceLock`] to uninitialized if the initializer unwinds.
+        struct ResetOnUnwind<'a>(&'a AtomicU8);
+
+        impl Drop for ResetOnUnwind<'_> {
+            fn drop(&mut self) {
+                self.0.store(ONCE_UNINIT, Ordering::Release);
+            }
+        }
+
+        let reset = ResetOnUnwind(&self.state);
+        let value = f();
+        core::mem::forget(reset);
+
+        // SAFETY:
+        // This context moved `state` out of [`ONCE_UNINIT`], so it has exclusive access to
+        // `value`.
+        let value = unsafe { (*self.value.get()).write(value) };
+        self.state.store(ONCE_COMPLETE, Ordering::Release);
+
+        value
+    }
+
+    /// Returns a reference to the value without checking that it has been initialized.
+    ///
+    /// # Safety
+    /// `state` must be [`ONCE_COMPLETE`] and that must have been observed with
+    /// [`Ordering::Acquire`].
+    unsafe fn get_unchecked(&self) -> &T {
+        // SAFETY:
+        // According to the invariants of this function, `value` is never mutated again.
+        let value = unsafe { &*self.value.get() };
+
+        // SAFETY:
+        // According to the invariants of this function, `value` has been initialized.
+        unsafe { value.assume_init_ref() }
+    }
+}
+
+impl<T> Default for OnceLock<T> {
+    fn default() -> Self {
+        OnceLock::new()
+    }
+}
+
+impl<T> Drop for OnceLock<T> {
+    fn drop(&mut self) {
+        if *self.state.get_mut() == ONCE_COMPLETE {
+            // SAFETY:
+            // `state` is [`ONCE_COMPLETE`], so `value` has been initialized.
+            unsafe { self.value.get_mut().assume_init_drop() }
+        }
+    }
+}
+
+/// A value which is initialized on the first access.
+///
+/// The initializer runs exactly once, even if multiple contexts access the [`Lazy`]
+/// simultaneously; the other contexts spin until initialization completes.
+pub struct Lazy<T, F = fn() -> T> {
+    /// The initialized value.
+    cell: OnceLock<T>,
+    /// The function used to initialize the [`Lazy`], taken when initialization starts.
+    init: UnsafeCell<Option<F>>,
+}
+
+// SAFETY:
+// `init` is only accessed by the single context initializing `cell`, so sharing a [`Lazy`] only
+// shares `&T` between contexts and moves `F` to the initializing context.
+unsafe impl<T: Send + Sync, F: Send> Sync for Lazy<T, F> {}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    /// Creates a new [`Lazy`] which will be initialized with `init` on first access.
+    pub const fn new(init: F) -> Lazy<T, F> {
+        Lazy {
+            cell: OnceLock::new(),
+            init: UnsafeCell::new(Some(init)),
+        }
+    }
+
+    /// Forces the evaluation of `this`, returning a reference to the result.
+    ///
+    /// # Panics
+    /// If the initializer of `this` panicked, either during this call or a previous one.
+    pub fn force(this: &Lazy<T, F>) -> &T {
+        this.cell.get_or_init(|| {
+            // SAFETY:
+            // Only the context initializing `cell` runs this closure, so this access is exclusive.
+            let init = unsafe { (*this.init.get()).take() };
+
+            match init {
+                Some(init) => init(),
+                None => panic!("Lazy instance has previously been poisoned"),
+            }
+        })
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        Lazy::force(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        panic::{self, AssertUnwindSafe},
+        sync::atomic::{AtomicUsize, Ordering},
+        thread,
+    };
+
+    use super::{Lazy, OnceLock};
+
+    #[test]
+    fn lazy_initializes_once() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let lazy = Lazy::new(|| {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+            5u64
+        });
+
+        assert_eq!(CALLS.load(Ordering::Relaxed), 0);
+        assert_eq!(*lazy, 5);
+        assert_eq!(*lazy, 5);
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn lazy_static_initializes_once_across_threads() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        static LAZY: Lazy<u64> = Lazy::new(|| {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+            thread::yield_now();
+            7
+        });
+
+        let threads = (0..8).map(|_| thread::spawn(|| *LAZY)).collect::<Vec<_>>();
+
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), 7);
+        }
+
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn lazy_poisoned_by_panicking_initializer() {
+        let lazy = Lazy::<u64, _>::new(|| panic!("initializer failed"));
+
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| *lazy)).is_err());
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| *lazy)).is_err());
+    }
+
+    #[test]
+    fn once_lock_get_and_set() {
+        let cell = OnceLock::new();
+
+        assert_eq!(cell.get(), None);
+        assert_eq!(cell.set(1u64), Ok(()));
+        assert_eq!(cell.set(2u64), Err(2));
+        assert_eq!(cell.get(), Some(&1));
+    }
+
+    #[test]
+    fn once_lock_concurrent_set() {
+        static CELL: OnceLock<usize> = OnceLock::new();
+
+        let threads = (0..8)
+            .map(|index| thread::spawn(move || CELL.set(index).is_ok()))
+            .collect::<Vec<_>>();
+
+        let winners = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .filter(|&won| won)
+            .count();
+
+        assert_eq!(winners, 1);
+        assert!(CELL.get().is_some());
+    }
+
+    #[test]
+    fn once_lock_get_or_init_runs_once() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        static CELL: OnceLock<u64> = OnceLock::new();
+
+        let threads = (0..8)
+            .map(|_| {
+                thread::spawn(|| {
+                    *CELL.get_or_init(|| {
+                        CALLS.fetch_add(1, Ordering::Relaxed);
+                        thread::yield_now();
+                        9
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), 9);
+        }
+
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn once_lock_retries_after_panicking_initializer() {
+        let cell = OnceLock::new();
+
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| {
+            cell.get_or_init(|| panic!("initializer failed"))
+        }))
+        .is_err());
+        assert_eq!(cell.get(), None);
+        assert_eq!(*cell.get_or_init(|| 3u64), 3);
+    }
+}
@@ -1,29 +1,41 @@
 //! Zeta kernel logging.
 
-use core::sync::atomic::{AtomicU8, Ordering};
+use core::{
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
-use crate::spinlock::RawSpinLock;
+use crate::{
+    atomic::{AtomicEnum, U8Enum},
+    spinlock::RawSpinLock,
+};
 
 /// The filter on logging that is currently active.
-static LEVEL_FILTER: AtomicU8 = AtomicU8::new(LogFilter::Off as u8);
+static LEVEL_FILTER: AtomicEnum<LogFilter> = AtomicEnum::from_raw(LogFilter::Off as u8);
 
 /// Sets the global [`LogFilter`].
 pub fn set_filter(level: LogFilter) {
-    LEVEL_FILTER.store(level as u8, Ordering::Relaxed);
+    LEVEL_FILTER.store(level, Ordering::Relaxed);
 }
 
 /// Returns the currently active global [`LogFilter`].
 pub fn get_filter() -> LogFilter {
-    match LEVEL_FILTER.load(Ordering::Relaxed) {
-        TRACE => LogFilter::Trace,
-        DEBUG => LogFilter::Debug,
-        INFO => LogFilter::Info,
-        WARN => LogFilter::Warn,
-        ERROR => LogFilter::Error,
-        FATAL => LogFilter::Fatal,
-        OFF => LogFilter::Off,
-        _ => unreachable!(),
-    }
+    LEVEL_FILTER.load(Ordering::Relaxed)
+}
+
+/// Whether level tags should be wrapped in ANSI SGR color sequences.
+static COLOR: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether [`Logger`]s should emit colored level tags.
+///
+/// This should only be enabled when the active [`Logger`]'s sink interprets ANSI SGR sequences.
+pub fn set_color(enabled: bool) {
+    COLOR.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns `true` if [`Logger`]s should emit colored level tags.
+pub fn color_enabled() -> bool {
+    COLOR.load(Ordering::Relaxed)
 }
 
 /// The active logger.
@@ -43,6 +55,24 @@ pub fn log_fmt(level: LogLevel, args: core::fmt::Arguments) {
     LOCK.unlock();
 }
 
+/// Writes `args` to the current global [`Logger`] at `level` severity from the panic handler.
+///
+/// Unlike [`log_fmt()`], this never waits for [`LOCK`]. The kernel only executes in a single
+/// context, so if [`LOCK`] is held, it is held by the code that panicked, which will never release
+/// it. In that case the [`Logger`] is used without the lock, and [`LOCK`] is left held.
+pub fn log_fmt_panicking(level: LogLevel, args: core::fmt::Arguments) {
+    let acquired = LOCK.try_lock().is_ok();
+
+    // SAFETY:
+    // Either [`LOCK`] is held by this call, or it is held by the panicking code, which will never
+    // resume and access [`LOGGER`] again.
+    unsafe { LOGGER.log(level, args) };
+
+    if acquired {
+        LOCK.unlock();
+    }
+}
+
 /// Sets the current global [`Logger`].
 pub fn set_logger(logger: &'static mut dyn Logger) {
     LOCK.lock();
@@ -182,6 +212,72 @@ pub enum LogLevel {
     Fatal = LogFilter::Fatal as u8,
 }
 
+impl U8Enum for LogFilter {
+    fn into_u8(self) -> u8 {
+        self as u8
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        let filter = match value {
+            TRACE => LogFilter::Trace,
+            DEBUG => LogFilter::Debug,
+            INFO => LogFilter::Info,
+            WARN => LogFilter::Warn,
+            ERROR => LogFilter::Error,
+            FATAL => LogFilter::Fatal,
+            OFF => LogFilter::Off,
+            _ => return None,
+        };
+
+        Some(filter)
+    }
+}
+
+impl LogLevel {
+    /// Returns the ANSI SGR parameters used to color tags of this [`LogLevel`].
+    pub const fn sgr_parameters(self) -> &'static str {
+        match self {
+            LogLevel::Trace => "90",
+            LogLevel::Debug => "36",
+            LogLevel::Info => "32",
+            LogLevel::Warn => "33",
+            LogLevel::Error => "31",
+            LogLevel::Fatal => "1;31",
+        }
+    }
+}
+
+/// Formats the tag that precedes a log message of a specific [`LogLevel`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LevelTag {
+    /// The [`LogLevel`] of the tag.
+    level: LogLevel,
+    /// Whether the tag should be wrapped in ANSI SGR color sequences.
+    color: bool,
+}
+
+impl LevelTag {
+    /// Creates a new [`LevelTag`] for `level`, colored if `color` is `true`.
+    pub const fn new(level: LogLevel, color: bool) -> LevelTag {
+        LevelTag { level, color }
+    }
+}
+
+impl fmt::Display for LevelTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.color {
+            write!(
+                f,
+                "\x1b[{}m[{:?}]\x1b[0m",
+                self.level.sgr_parameters(),
+                self.level
+            )
+        } else {
+            write!(f, "[{:?}]", self.level)
+        }
+    }
+}
+
 /// Controls which logs are outputted.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[repr(u8)]
@@ -202,3 +298,65 @@ pub enum LogFilter {
     /// Disables all logs.
     Off = OFF,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{string::String, sync::Mutex};
+
+    use super::{log_fmt_panicking, set_logger, LevelTag, LogLevel, Logger, LOCK};
+
+    /// The output of [`RecordingLogger`].
+    static RECORDED: Mutex<String> = Mutex::new(String::new());
+
+    /// A [`Logger`] that appends every message to [`RECORDED`].
+    struct RecordingLogger;
+
+    impl Logger for RecordingLogger {
+        fn log(&mut self, level: LogLevel, args: core::fmt::Arguments) {
+            use core::fmt::Write;
+
+            let mut recorded = RECORDED.lock().unwrap();
+            write!(recorded, "{level:?}: {args}").unwrap();
+        }
+    }
+
+    #[test]
+    fn panicking_log_does_not_wait_for_held_lock() {
+        set_logger(std::boxed::Box::leak(std::boxed::Box::new(RecordingLogger)));
+
+        // Simulate a panic raised while a normal log call holds the lock.
+        LOCK.lock();
+        log_fmt_panicking(LogLevel::Fatal, format_args!("while locked"));
+        assert!(LOCK.try_lock().is_err());
+        LOCK.unlock();
+
+        log_fmt_panicking(LogLevel::Fatal, format_args!(", unlocked"));
+        assert!(LOCK.try_lock().is_ok());
+        LOCK.unlock();
+
+        assert_eq!(
+            *RECORDED.lock().unwrap(),
+            "Fatal: while lockedFatal: , unlocked"
+        );
+    }
+
+    #[test]
+    fn level_tag_without_color() {
+        let tag = LevelTag::new(LogLevel::Error, false).to_string();
+
+        assert_eq!(tag, "[Error]");
+        assert!(!tag.contains('\x1b'));
+    }
+
+    #[test]
+    fn level_tag_with_color() {
+        assert_eq!(
+            LevelTag::new(LogLevel::Error, true).to_string(),
+            "\x1b[31m[Error]\x1b[0m"
+        );
+        assert_eq!(
+            LevelTag::new(LogLevel::Info, true).to_string(),
+            "\x1b[32m[Info]\x1b[0m"
+        );
+    }
+}
@@ -5,21 +5,102 @@
 #![feature(strict_provenance, optimize_attribute)]
 
 pub mod arch;
+pub mod atomic;
 pub mod cells;
 pub mod logging;
 pub mod polyfill;
+pub mod rwlock;
 pub mod spinlock;
 pub mod utils;
 
+use core::{
+    fmt,
+    panic::Location,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
 #[cfg(test)]
 fn main() {}
 
+/// Set once a panic has started being reported, so that a panic while reporting a panic does not
+/// recurse into the logger.
+#[cfg_attr(test, allow(dead_code))]
+static PANICKING: AtomicBool = AtomicBool::new(false);
+
 /// Function that handles panics.
 #[cfg_attr(not(test), panic_handler)]
 fn panic(info: &core::panic::PanicInfo) -> ! {
-    log_fatal!("{}", info);
+    if !PANICKING.swap(true, Ordering::Relaxed) {
+        // The panic may have been raised while the logger was locked, so never wait for it.
+        logging::log_fmt_panicking(
+            logging::LogLevel::Fatal,
+            format_args!("{}\n", PanicReport::new(info.message(), info.location())),
+        );
+    }
 
     loop {
         core::hint::spin_loop();
     }
 }
+
+/// The human-readable report of a panic.
+struct PanicReport<'a, M> {
+    /// The message the panic was raised with.
+    message: M,
+    /// The location at which the panic was raised, if known.
+    location: Option<&'a Location<'a>>,
+}
+
+impl<'a, M: fmt::Display> PanicReport<'a, M> {
+    /// Creates a new [`PanicReport`] for a panic raised at `location` with `message`.
+    fn new(message: M, location: Option<&'a Location<'a>>) -> PanicReport<'a, M> {
+        PanicReport { message, location }
+    }
+}
+
+impl<M: fmt::Display> fmt::Display for PanicReport<'_, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Some(location) => write!(
+                f,
+                "panicked at {}:{}:{}:\n{}",
+                location.file(),
+                location.line(),
+                location.column(),
+                self.message
+            ),
+            None => write!(f, "panicked:\n{}", self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::panic::Location;
+
+    use super::PanicReport;
+
+    #[test]
+    fn panic_report_with_location() {
+        let location = Location::caller();
+        let report = PanicReport::new("kernel exploded", Some(location));
+
+        assert_eq!(
+            report.to_string(),
+            format!(
+                "panicked at {}:{}:{}:\nkernel exploded",
+                location.file(),
+                location.line(),
+                location.column()
+            )
+        );
+    }
+
+    #[test]
+    fn panic_report_without_location() {
+        assert_eq!(
+            PanicReport::new(format_args!("value was {}", 5), None).to_string(),
+            "panicked:\nvalue was 5"
+        );
+    }
+}
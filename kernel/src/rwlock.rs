@@ -0,0 +1,374 @@
+//! A simple [`SpinRwLock`] for the kernel.
+
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::spinlock::{Backoff, SpinLockAcquisitionError};
+
+/// The bit of [`SpinRwLock::state`] set while a writer holds, or is waiting for, the lock.
+const WRITER: usize = 1 << (usize::BITS - 1);
+/// The bits of [`SpinRwLock::state`] counting the readers holding the lock.
+const READERS: usize = WRITER - 1;
+
+/// A reader-writer lock useful for protecting shared data that is mostly read.
+///
+/// This lock allows any number of readers or a single writer at a time, and will spin waiting for
+/// the lock to become available. Once a writer starts waiting, new readers wait until it is done.
+pub struct SpinRwLock<T: ?Sized> {
+    /// The [`WRITER`] flag and the number of [`READERS`].
+    state: AtomicUsize,
+    /// The value protected by the [`SpinRwLock`].
+    value: UnsafeCell<T>,
+}
+
+// SAFETY:
+// Nothing about `SpinRwLock<T>` changes whether it
+// is safe to send `T` across threads.
+unsafe impl<T: ?Sized + Send> Send for SpinRwLock<T> {}
+
+// SAFETY:
+// Readers on multiple threads share `&T`, so `T` must be `Sync`,
+// and a writer may obtain `&mut T` from any thread, so `T` must be `Send`.
+unsafe impl<T: ?Sized + Send + Sync> Sync for SpinRwLock<T> {}
+
+impl<T> SpinRwLock<T> {
+    /// Creates a new [`SpinRwLock`] in an unlocked state ready for use.
+    pub const fn new(value: T) -> SpinRwLock<T> {
+        SpinRwLock {
+            state: AtomicUsize::new(0),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes this lock, returning the underlying data.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> SpinRwLock<T> {
+    /// Acquires shared read access, spinning until it is able to do so.
+    ///
+    /// Upon returning, no writer holds the lock. A RAII guard is returned to allow scoped unlock
+    /// of the lock.
+    pub fn read(&self) -> SpinRwLockReadGuard<'_, T> {
+        let mut backoff = Backoff::new();
+
+        loop {
+            match self.try_read() {
+                Ok(guard) => return guard,
+                Err(SpinLockAcquisitionError) => backoff.spin(),
+            }
+        }
+    }
+
+    /// Attempts to acquire shared read access.
+    ///
+    /// This function does not spin or block.
+    ///
+    /// # Errors
+    /// If a writer holds or is waiting for the [`SpinRwLock`], then this call will return an
+    /// [`Err`].
+    pub fn try_read(&self) -> Result<SpinRwLockReadGuard<'_, T>, SpinLockAcquisitionError> {
+        let mut state = self.state.load(Ordering::Relaxed);
+
+        // Only retry while the failure was caused by other readers changing the count.
+        while state & WRITER == 0 && state & READERS != READERS {
+            match self.state.compare_exchange_weak(
+                state,
+                state + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Ok(SpinRwLockReadGuard { lock: self }),
+                Err(current) => state = current,
+            }
+        }
+
+        Err(SpinLockAcquisitionError)
+    }
+
+    /// Acquires exclusive write access, spinning until it is able to do so.
+    ///
+    /// This function first claims the [`SpinRwLock`] for writing, preventing new readers, then
+    /// spins until the existing readers have released it. Upon returning, the context is the only
+    /// context with the lock held. A RAII guard is returned to allow scoped unlock of the lock.
+    pub fn write(&self) -> SpinRwLockWriteGuard<'_, T> {
+        let mut backoff = Backoff::new();
+
+        loop {
+            let state = self.state.load(Ordering::Relaxed);
+            if state & WRITER == 0
+                && self
+                    .state
+                    .compare_exchange_weak(
+                        state,
+                        state | WRITER,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            {
+                break;
+            }
+
+            backoff.spin();
+        }
+
+        let mut backoff = Backoff::new();
+        while self.state.load(Ordering::Acquire) & READERS != 0 {
+            backoff.spin();
+        }
+
+        SpinRwLockWriteGuard { lock: self }
+    }
+
+    /// Attempts to acquire exclusive write access.
+    ///
+    /// This function does not spin or block.
+    ///
+    /// # Errors
+    /// If any reader or writer holds the [`SpinRwLock`], then this call will return an [`Err`].
+    pub fn try_write(&self) -> Result<SpinRwLockWriteGuard<'_, T>, SpinLockAcquisitionError> {
+        self.state
+            .compare_exchange(0, WRITER, Ordering::Acquire, Ordering::Relaxed)
+            .map(|_| SpinRwLockWriteGuard { lock: self })
+            .map_err(|_| SpinLockAcquisitionError)
+    }
+
+    /// Returns a mutable reference to the underlying data.
+    ///
+    /// Since this call borrows the [`SpinRwLock`] mutably, no actual locking needs to take place
+    /// - the mutable borrow statically guarantees no locks exist.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for SpinRwLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("SpinRwLock");
+
+        // Never spin here, since a writer holding the lock may be the one formatting it.
+        match self.try_read() {
+            Ok(guard) => debug.field("value", &&*guard),
+            Err(SpinLockAcquisitionError) => debug.field("value", &format_args!("<locked>")),
+        };
+
+        debug.finish_non_exhaustive()
+    }
+}
+
+impl<T: Default> Default for SpinRwLock<T> {
+    fn default() -> Self {
+        SpinRwLock::new(T::default())
+    }
+}
+
+/// A RAII implementation of a scoped shared read lock of a [`SpinRwLock`]. When this structure is
+/// dropped, the shared access will be released.
+///
+/// This structure is created by the [`SpinRwLock::read()`] and [`SpinRwLock::try_read()`]
+/// methods.
+pub struct SpinRwLockReadGuard<'a, T: ?Sized> {
+    /// The [`SpinRwLock`] with which this [`SpinRwLockReadGuard`] is associated.
+    lock: &'a SpinRwLock<T>,
+}
+
+impl<T: ?Sized> Deref for SpinRwLockReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        let value_ptr = self.lock.value.get();
+
+        // SAFETY:
+        // No writer holds the lock while a reader does, so the value is not mutated.
+        unsafe { &*value_ptr }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for SpinRwLockReadGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Drop for SpinRwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.state.fetch_sub(1, Ordering::Release);
+    }
+}
+
+/// A RAII implementation of a scoped exclusive write lock of a [`SpinRwLock`]. When this structure
+/// is dropped, the lock will be unlocked.
+///
+/// This structure is created by the [`SpinRwLock::write()`] and [`SpinRwLock::try_write()`]
+/// methods.
+pub struct SpinRwLockWriteGuard<'a, T: ?Sized> {
+    /// The [`SpinRwLock`] with which this [`SpinRwLockWriteGuard`] is associated.
+    lock: &'a SpinRwLock<T>,
+}
+
+impl<T: ?Sized> Deref for SpinRwLockWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        let value_ptr = self.lock.value.get();
+
+        // SAFETY:
+        // We have exclusive access to the value pointed to by `value_ptr`.
+        unsafe { &*value_ptr }
+    }
+}
+
+impl<T: ?Sized> DerefMut for SpinRwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let value_ptr = self.lock.value.get();
+
+        // SAFETY:
+        // We have exclusive access to the value pointed to by `value_ptr`.
+        unsafe { &mut *value_ptr }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for SpinRwLockWriteGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Drop for SpinRwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        // Readers never register while the writer flag is set, so the writer is the only holder.
+        self.lock.state.store(0, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Barrier},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use super::SpinRwLock;
+    use crate::spinlock::SpinLockAcquisitionError;
+
+    /// The number of threads used in the stress tests.
+    const THREAD_COUNT: usize = 8;
+    /// The number of increments each thread performs in the stress tests.
+    const INCREMENTS_PER_THREAD: u64 = 10_000;
+
+    #[test]
+    fn multiple_read_guards() {
+        let lock = SpinRwLock::new(5u64);
+
+        let first = lock.read();
+        let second = lock.read();
+        let third = lock.try_read().unwrap();
+
+        assert_eq!(*first + *second + *third, 15);
+        assert_eq!(lock.try_write().err(), Some(SpinLockAcquisitionError));
+
+        drop((first, second, third));
+        assert!(lock.try_write().is_ok());
+    }
+
+    #[test]
+    fn readers_hold_lock_simultaneously() {
+        let lock = Arc::new(SpinRwLock::new(7u64));
+        let barrier = Arc::new(Barrier::new(THREAD_COUNT));
+
+        let threads = (0..THREAD_COUNT)
+            .map(|_| {
+                let lock = Arc::clone(&lock);
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    let guard = lock.read();
+                    // Every thread must reach this point while holding its read guard.
+                    barrier.wait();
+                    *guard
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), 7);
+        }
+    }
+
+    #[test]
+    fn try_read_fails_while_written() {
+        let lock = SpinRwLock::new(0u64);
+
+        let mut guard = lock.write();
+        *guard += 1;
+        assert_eq!(lock.try_read().err(), Some(SpinLockAcquisitionError));
+        assert_eq!(lock.try_write().err(), Some(SpinLockAcquisitionError));
+        drop(guard);
+
+        assert_eq!(*lock.read(), 1);
+    }
+
+    #[test]
+    fn writer_waits_for_readers() {
+        let lock = Arc::new(SpinRwLock::new(0u64));
+
+        let guard = lock.read();
+
+        let writer = {
+            let lock = Arc::clone(&lock);
+            thread::spawn(move || *lock.write() += 1)
+        };
+
+        // Wait until the writer has claimed the lock and is waiting for the reader.
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while lock.try_read().is_ok() {
+            assert!(Instant::now() < deadline, "writer never claimed the lock");
+            core::hint::spin_loop();
+        }
+        assert_eq!(*guard, 0);
+        drop(guard);
+
+        writer.join().unwrap();
+        assert_eq!(*lock.read(), 1);
+    }
+
+    #[test]
+    fn concurrent_writes() {
+        let lock = Arc::new(SpinRwLock::new(0u64));
+
+        let threads = (0..THREAD_COUNT)
+            .map(|_| {
+                let lock = Arc::clone(&lock);
+                thread::spawn(move || {
+                    for _ in 0..INCREMENTS_PER_THREAD {
+                        *lock.write() += 1;
+                        let _ = *lock.read();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(*lock.read(), THREAD_COUNT as u64 * INCREMENTS_PER_THREAD);
+    }
+
+    #[test]
+    fn debug_locked() {
+        let lock = SpinRwLock::new(3u64);
+
+        assert_eq!(format!("{lock:?}"), "SpinRwLock { value: 3, .. }");
+
+        let _guard = lock.write();
+        assert_eq!(format!("{lock:?}"), "SpinRwLock { value: <locked>, .. }");
+    }
+}
@@ -2,14 +2,24 @@
 
 use core::{
     cell::UnsafeCell,
+    fmt,
+    marker::PhantomData,
+    mem::ManuallyDrop,
     ops::{Deref, DerefMut},
+    ptr::NonNull,
     sync::atomic::{AtomicBool, Ordering},
 };
 
+#[cfg(debug_assertions)]
+use core::sync::atomic::AtomicUsize;
+
 /// The locking component of a [`SpinLock`].
 pub struct RawSpinLock {
     /// The lock.
     lock: AtomicBool,
+    /// The context currently holding the lock, or 0 if the lock is not held.
+    #[cfg(debug_assertions)]
+    owner: AtomicUsize,
 }
 
 impl RawSpinLock {
@@ -17,27 +27,28 @@ impl RawSpinLock {
     pub const fn new() -> RawSpinLock {
         RawSpinLock {
             lock: AtomicBool::new(false),
+            #[cfg(debug_assertions)]
+            owner: AtomicUsize::new(0),
         }
     }
 
     /// Locks the [`RawSpinLock`], spinning until the lock is acquired.
     ///
     /// This function does not return until the lock has been acquired.
+    ///
+    /// # Panics
+    /// In debug builds, if the current context already holds the lock, since this call would
+    /// otherwise never return.
     pub fn lock(&self) {
-        let mut was_locked = self.lock.load(Ordering::Relaxed);
-
-        loop {
-            if !was_locked {
-                match self
-                    .lock
-                    .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
-                {
-                    Ok(_) => break,
-                    Err(state) => was_locked = state,
-                }
-            }
+        #[cfg(debug_assertions)]
+        if self.owner.load(Ordering::Relaxed) == current_context() {
+            panic!("deadlock: mutex locked twice by same context.");
+        }
 
-            core::hint::spin_loop();
+        let mut backoff = Backoff::new();
+
+        while self.try_lock().is_err() {
+            backoff.spin();
         }
     }
 
@@ -54,6 +65,9 @@ impl RawSpinLock {
                 .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
                 .is_ok()
         {
+            #[cfg(debug_assertions)]
+            self.owner.store(current_context(), Ordering::Relaxed);
+
             Ok(())
         } else {
             Err(SpinLockAcquisitionError)
@@ -62,6 +76,9 @@ impl RawSpinLock {
 
     /// Method to make unlocking of a mutex more explicit.
     pub fn unlock(&self) {
+        #[cfg(debug_assertions)]
+        self.owner.store(0, Ordering::Relaxed);
+
         self.lock.store(false, Ordering::Release);
     }
 }
@@ -72,6 +89,57 @@ impl Default for RawSpinLock {
     }
 }
 
+/// Returns a non-zero token identifying the current execution context.
+///
+/// The kernel currently only ever executes in a single context, so every context shares the same
+/// token. This must be revisited once multiple processors or interrupt handlers take locks.
+#[cfg(all(debug_assertions, not(test)))]
+fn current_context() -> usize {
+    1
+}
+
+/// Returns a non-zero token identifying the current execution context.
+///
+/// When testing on the host, each thread is a separate context.
+#[cfg(all(debug_assertions, test))]
+fn current_context() -> usize {
+    std::thread_local! {
+        /// A per-thread value whose address identifies the thread.
+        static CONTEXT: u8 = const { 0 };
+    }
+
+    CONTEXT.with(|context| core::ptr::from_ref(context).addr())
+}
+
+/// The maximum number of [`core::hint::spin_loop()`] hints issued by a single [`Backoff::spin()`].
+const MAX_BACKOFF_SPINS: u32 = 64;
+
+/// Exponential backoff used while waiting for a contended lock.
+///
+/// Each call to [`Backoff::spin()`] issues twice as many [`core::hint::spin_loop()`] hints as the
+/// previous call, up to [`MAX_BACKOFF_SPINS`], reducing traffic on the contended cache line.
+pub(crate) struct Backoff {
+    /// The number of [`core::hint::spin_loop()`] hints the next [`Backoff::spin()`] will issue.
+    spins: u32,
+}
+
+impl Backoff {
+    /// Creates a new [`Backoff`] that starts with a single hint.
+    pub(crate) const fn new() -> Backoff {
+        Backoff { spins: 1 }
+    }
+
+    /// Spins for the current number of hints, then increases the number of hints for the
+    /// next call.
+    pub(crate) fn spin(&mut self) {
+        for _ in 0..self.spins {
+            core::hint::spin_loop();
+        }
+
+        self.spins = (self.spins * 2).min(MAX_BACKOFF_SPINS);
+    }
+}
+
 /// A mutual exclusion primitive useful to protecting shared data.
 ///
 /// This mutex will spin waiting for the lock to become available.
@@ -143,6 +211,32 @@ impl<T: ?Sized> SpinLock<T> {
     pub fn get_mut(&mut self) -> &mut T {
         self.value.get_mut()
     }
+
+    /// Returns a raw pointer to the underlying data without locking.
+    ///
+    /// The pointer is stable for the lifetime of the [`SpinLock`]. This exists only for building
+    /// higher-level primitives or handing a stable address to firmware, where the caller
+    /// guarantees exclusivity by some other means.
+    ///
+    /// Dereferencing the returned pointer while this context does not hold the lock is undefined
+    /// behavior unless access is otherwise synchronized.
+    pub fn data_ptr(&self) -> *mut T {
+        self.value.get()
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for SpinLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("SpinLock");
+
+        // Never spin here, since the holder of the lock may be the one formatting it.
+        match self.try_lock() {
+            Ok(guard) => debug.field("value", &&*guard),
+            Err(SpinLockAcquisitionError) => debug.field("value", &format_args!("<locked>")),
+        };
+
+        debug.finish_non_exhaustive()
+    }
 }
 
 /// A RAII implementation of a "scoped lock" of a [`SpinLock`]. When this structure is dropped, the
@@ -179,12 +273,297 @@ impl<T: ?Sized> DerefMut for SpinLockGuard<'_, T> {
     }
 }
 
+impl<T: ?Sized + fmt::Debug> fmt::Debug for SpinLockGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
 impl<T: ?Sized> Drop for SpinLockGuard<'_, T> {
     fn drop(&mut self) {
         self.mutex.lock.unlock();
     }
 }
 
+impl<'a, T: ?Sized> SpinLockGuard<'a, T> {
+    /// Makes a [`MappedSpinLockGuard`] for a component of the locked data.
+    ///
+    /// The [`SpinLock`] remains locked until the returned [`MappedSpinLockGuard`] is dropped.
+    ///
+    /// This is an associated function that needs to be used as `SpinLockGuard::map(...)`, so
+    /// that it does not conflict with methods on `T`.
+    pub fn map<U: ?Sized, F: FnOnce(&mut T) -> &mut U>(
+        mut guard: SpinLockGuard<'a, T>,
+        f: F,
+    ) -> MappedSpinLockGuard<'a, U> {
+        // If `f` panics, `guard` is dropped normally and the lock is released.
+        let value = NonNull::from(f(&mut guard));
+        let guard = ManuallyDrop::new(guard);
+
+        MappedSpinLockGuard {
+            lock: &guard.mutex.lock,
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A RAII guard returned by [`SpinLockGuard::map()`], which provides access to a component of the
+/// data protected by a [`SpinLock`]. When this structure is dropped, the [`SpinLock`] will be
+/// unlocked.
+#[allow(clippy::module_name_repetitions)]
+pub struct MappedSpinLockGuard<'a, T: ?Sized> {
+    /// The lock held by this [`MappedSpinLockGuard`].
+    lock: &'a RawSpinLock,
+    /// The component of the locked data.
+    value: NonNull<T>,
+    /// Marks this [`MappedSpinLockGuard`] as holding exclusive access to `T`.
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY:
+// A `MappedSpinLockGuard` only provides access to `T` through references, so it is as safe to
+// share as `&T`.
+unsafe impl<T: ?Sized + Sync> Sync for MappedSpinLockGuard<'_, T> {}
+
+impl<'a, T: ?Sized> MappedSpinLockGuard<'a, T> {
+    /// Makes a [`MappedSpinLockGuard`] for a component of the already mapped data.
+    ///
+    /// This is an associated function that needs to be used as `MappedSpinLockGuard::map(...)`,
+    /// so that it does not conflict with methods on `T`.
+    pub fn map<U: ?Sized, F: FnOnce(&mut T) -> &mut U>(
+        mut guard: MappedSpinLockGuard<'a, T>,
+        f: F,
+    ) -> MappedSpinLockGuard<'a, U> {
+        // If `f` panics, `guard` is dropped normally and the lock is released.
+        let value = NonNull::from(f(&mut guard));
+        let guard = ManuallyDrop::new(guard);
+
+        MappedSpinLockGuard {
+            lock: guard.lock,
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for MappedSpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // `value` was derived from the data protected by `lock`, which we hold.
+        unsafe { self.value.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MappedSpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY:
+        // `value` was derived from the data protected by `lock`, which we hold exclusively.
+        unsafe { self.value.as_mut() }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MappedSpinLockGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized> Drop for MappedSpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.unlock();
+    }
+}
+
 /// Represents the failure to acquire a spinlock.
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SpinLockAcquisitionError;
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread, time::Duration};
+
+    use super::{
+        Backoff, MappedSpinLockGuard, SpinLock, SpinLockAcquisitionError, SpinLockGuard,
+        MAX_BACKOFF_SPINS,
+    };
+
+    /// The number of threads used in the stress tests.
+    const THREAD_COUNT: usize = 8;
+    /// The number of increments each thread performs in the stress tests.
+    const INCREMENTS_PER_THREAD: u64 = 10_000;
+
+    #[test]
+    fn backoff_grows_exponentially() {
+        let mut backoff = Backoff::new();
+
+        let mut expected = 1;
+        while expected < MAX_BACKOFF_SPINS {
+            assert_eq!(backoff.spins, expected);
+            backoff.spin();
+            expected *= 2;
+        }
+
+        backoff.spin();
+        assert_eq!(backoff.spins, MAX_BACKOFF_SPINS);
+    }
+
+    #[test]
+    fn uncontended_lock() {
+        let lock = SpinLock::new(0u64);
+
+        *lock.lock() += 1;
+        *lock.lock() += 1;
+
+        assert_eq!(lock.into_inner(), 2);
+    }
+
+    #[test]
+    fn try_lock_fails_while_held() {
+        let lock = SpinLock::new(0u64);
+
+        let guard = lock.lock();
+        assert_eq!(lock.try_lock().err(), Some(SpinLockAcquisitionError));
+        drop(guard);
+
+        assert!(lock.try_lock().is_ok());
+    }
+
+    #[test]
+    fn contended_lock_waits_for_release() {
+        let lock = Arc::new(SpinLock::new(0u64));
+
+        let mut guard = lock.lock();
+
+        let waiter = {
+            let lock = Arc::clone(&lock);
+            thread::spawn(move || {
+                let mut guard = lock.lock();
+                assert_eq!(*guard, 1);
+                *guard += 1;
+            })
+        };
+
+        // Give the waiter a chance to start spinning on the held lock.
+        thread::sleep(Duration::from_millis(50));
+        *guard += 1;
+        drop(guard);
+
+        waiter.join().unwrap();
+        assert_eq!(*lock.lock(), 2);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "deadlock: mutex locked twice by same context.")]
+    fn double_lock_panics() {
+        let lock = SpinLock::new(0u64);
+
+        let _guard = lock.lock();
+        let _second = lock.lock();
+    }
+
+    #[test]
+    fn data_ptr_is_stable() {
+        let lock = SpinLock::new(0u64);
+
+        let ptr = lock.data_ptr();
+        assert_eq!(ptr, lock.data_ptr());
+
+        let mut guard = lock.lock();
+        assert_eq!(ptr, core::ptr::from_mut(&mut *guard));
+    }
+
+    #[test]
+    fn debug_unlocked() {
+        let lock = SpinLock::new(5u64);
+
+        assert_eq!(format!("{lock:?}"), "SpinLock { value: 5, .. }");
+    }
+
+    #[test]
+    fn debug_locked() {
+        let lock = SpinLock::new(5u64);
+
+        let guard = lock.lock();
+        assert_eq!(format!("{lock:?}"), "SpinLock { value: <locked>, .. }");
+        assert_eq!(format!("{guard:?}"), "5");
+    }
+
+    #[test]
+    fn concurrent_increments() {
+        let lock = Arc::new(SpinLock::new(0u64));
+
+        let threads = (0..THREAD_COUNT)
+            .map(|_| {
+                let lock = Arc::clone(&lock);
+                thread::spawn(move || {
+                    for _ in 0..INCREMENTS_PER_THREAD {
+                        *lock.lock() += 1;
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(*lock.lock(), THREAD_COUNT as u64 * INCREMENTS_PER_THREAD);
+    }
+
+    #[test]
+    fn concurrent_try_lock_increments() {
+        let lock = Arc::new(SpinLock::new(0u64));
+
+        let threads = (0..THREAD_COUNT)
+            .map(|_| {
+                let lock = Arc::clone(&lock);
+                thread::spawn(move || {
+                    let mut remaining = INCREMENTS_PER_THREAD;
+                    while remaining != 0 {
+                        if let Ok(mut guard) = lock.try_lock() {
+                            *guard += 1;
+                            remaining -= 1;
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(*lock.lock(), THREAD_COUNT as u64 * INCREMENTS_PER_THREAD);
+    }
+
+    #[test]
+    fn mapped_guard_projects_to_field() {
+        let lock = SpinLock::new((1u64, 2u64));
+
+        let mut second = SpinLockGuard::map(lock.lock(), |pair| &mut pair.1);
+        *second += 40;
+        assert_eq!(lock.try_lock().err(), Some(SpinLockAcquisitionError));
+
+        let mut second = MappedSpinLockGuard::map(second, |value| value);
+        *second += 1;
+        drop(second);
+
+        assert_eq!(lock.into_inner(), (1, 43));
+    }
+
+    #[test]
+    fn panicking_map_releases_lock() {
+        let lock = SpinLock::new(0u64);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            SpinLockGuard::map(lock.lock(), |_| -> &mut u64 { panic!("projection failed") })
+        }));
+
+        assert!(result.is_err());
+        assert!(lock.try_lock().is_ok());
+    }
+}
@@ -7,6 +7,23 @@ use core::ptr::NonNull;
 #[repr(transparent)]
 pub struct RawHandle(pub *mut core::ffi::c_void);
 
+impl RawHandle {
+    /// Returns a null [`RawHandle`].
+    pub const fn null() -> RawHandle {
+        RawHandle(core::ptr::null_mut())
+    }
+
+    /// Returns `true` if `self` is null.
+    pub fn is_null(self) -> bool {
+        self.0.is_null()
+    }
+
+    /// Returns the underlying pointer.
+    pub const fn as_ptr(self) -> *mut core::ffi::c_void {
+        self.0
+    }
+}
+
 /// A collection of related interfaces provided by UEFI firmware.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
@@ -22,6 +39,11 @@ impl Handle {
     pub fn as_raw(self) -> RawHandle {
         RawHandle(self.0.as_ptr())
     }
+
+    /// Returns the underlying non-null pointer.
+    pub const fn as_ptr(self) -> *mut core::ffi::c_void {
+        self.0.as_ptr()
+    }
 }
 
 /// A status code returned by UEFI functions.
@@ -402,9 +424,219 @@ pub struct Guid {
     pub data4: [u8; 8],
 }
 
+/// The layout of a point in time as reported by UEFI firmware.
+///
+/// The fields of a [`RawTime`] are not validated; use [`Time::new`] to obtain a validated [`Time`].
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[repr(C)]
+pub struct RawTime {
+    /// The year, in the range `1900..=9999`.
+    pub year: u16,
+    /// The month, in the range `1..=12`.
+    pub month: u8,
+    /// The day of the month, in the range `1..=31`.
+    pub day: u8,
+    /// The hour, in the range `0..=23`.
+    pub hour: u8,
+    /// The minute, in the range `0..=59`.
+    pub minute: u8,
+    /// The second, in the range `0..=59`.
+    pub second: u8,
+    /// Padding, must be zero.
+    pub _pad1: u8,
+    /// The nanosecond, in the range `0..=999_999_999`.
+    pub nanosecond: u32,
+    /// The offset from UTC in minutes, in the range `-1440..=1440`, or
+    /// [`Time::UNSPECIFIED_TIMEZONE`].
+    pub time_zone: i16,
+    /// A bitmask containing the daylight savings time information for the time.
+    pub daylight: u8,
+    /// Padding, must be zero.
+    pub _pad2: u8,
+}
+
+/// A validated point in time as reported by UEFI firmware.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Time(RawTime);
+
+impl Time {
+    /// The value of [`RawTime::time_zone`] indicating that the time is local time.
+    pub const UNSPECIFIED_TIMEZONE: i16 = 0x07FF;
+    /// The bit of [`RawTime::daylight`] indicating that the time is affected by daylight savings
+    /// time.
+    pub const ADJUST_DAYLIGHT: u8 = 0x01;
+    /// The bit of [`RawTime::daylight`] indicating that the time has been adjusted for daylight
+    /// savings time.
+    pub const IN_DAYLIGHT: u8 = 0x02;
+
+    /// Validates that each field of `raw` is within its allowed range.
+    ///
+    /// # Errors
+    /// Returns the [`InvalidTimeError`] corresponding to the first field that is out of range.
+    pub const fn new(raw: RawTime) -> Result<Time, InvalidTimeError> {
+        if raw.year < 1900 || raw.year > 9999 {
+            return Err(InvalidTimeError::Year);
+        }
+
+        let days_in_month = match raw.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if raw.year.is_multiple_of(4)
+                && (!raw.year.is_multiple_of(100) || raw.year.is_multiple_of(400)) =>
+            {
+                29
+            }
+            2 => 28,
+            _ => return Err(InvalidTimeError::Month),
+        };
+
+        if raw.day < 1 || raw.day > days_in_month {
+            return Err(InvalidTimeError::Day);
+        }
+
+        if raw.hour > 23 {
+            return Err(InvalidTimeError::Hour);
+        }
+
+        if raw.minute > 59 {
+            return Err(InvalidTimeError::Minute);
+        }
+
+        if raw.second > 59 {
+            return Err(InvalidTimeError::Second);
+        }
+
+        if raw.nanosecond > 999_999_999 {
+            return Err(InvalidTimeError::Nanosecond);
+        }
+
+        if (raw.time_zone < -1440 || raw.time_zone > 1440)
+            && raw.time_zone != Time::UNSPECIFIED_TIMEZONE
+        {
+            return Err(InvalidTimeError::TimeZone);
+        }
+
+        if raw.daylight & !(Time::ADJUST_DAYLIGHT | Time::IN_DAYLIGHT) != 0 {
+            return Err(InvalidTimeError::Daylight);
+        }
+
+        if raw._pad1 != 0 {
+            return Err(InvalidTimeError::Pad1);
+        }
+
+        if raw._pad2 != 0 {
+            return Err(InvalidTimeError::Pad2);
+        }
+
+        Ok(Time(raw))
+    }
+
+    /// Returns the year, in the range `1900..=9999`.
+    pub const fn year(self) -> u16 {
+        self.0.year
+    }
+
+    /// Returns the month, in the range `1..=12`.
+    pub const fn month(self) -> u8 {
+        self.0.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    pub const fn day(self) -> u8 {
+        self.0.day
+    }
+
+    /// Returns the hour, in the range `0..=23`.
+    pub const fn hour(self) -> u8 {
+        self.0.hour
+    }
+
+    /// Returns the minute, in the range `0..=59`.
+    pub const fn minute(self) -> u8 {
+        self.0.minute
+    }
+
+    /// Returns the second, in the range `0..=59`.
+    pub const fn second(self) -> u8 {
+        self.0.second
+    }
+
+    /// Returns the nanosecond, in the range `0..=999_999_999`.
+    pub const fn nanosecond(self) -> u32 {
+        self.0.nanosecond
+    }
+
+    /// Returns the offset from UTC in minutes, or [`None`] if the time is local time.
+    pub const fn time_zone(self) -> Option<i16> {
+        if self.0.time_zone == Time::UNSPECIFIED_TIMEZONE {
+            None
+        } else {
+            Some(self.0.time_zone)
+        }
+    }
+
+    /// Returns the daylight savings time bitmask.
+    pub const fn daylight(self) -> u8 {
+        self.0.daylight
+    }
+
+    /// Returns the underlying [`RawTime`].
+    pub const fn as_raw(self) -> RawTime {
+        self.0
+    }
+}
+
+impl core::fmt::Display for Time {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second()
+        )
+    }
+}
+
+/// The field of a [`RawTime`] that was out of range.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum InvalidTimeError {
+    /// The year was not in the range `1900..=9999`.
+    Year,
+    /// The month was not in the range `1..=12`.
+    Month,
+    /// The day was zero or past the end of the month.
+    Day,
+    /// The hour was greater than 23.
+    Hour,
+    /// The minute was greater than 59.
+    Minute,
+    /// The second was greater than 59.
+    Second,
+    /// The nanosecond was greater than 999,999,999.
+    Nanosecond,
+    /// The time zone was neither in the range `-1440..=1440` nor [`Time::UNSPECIFIED_TIMEZONE`].
+    TimeZone,
+    /// The daylight bitmask had bits set other than [`Time::ADJUST_DAYLIGHT`] and
+    /// [`Time::IN_DAYLIGHT`].
+    Daylight,
+    /// The first padding byte was non-zero.
+    Pad1,
+    /// The second padding byte was non-zero.
+    Pad2,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::datatypes::Char16;
+    extern crate std;
+
+    use std::string::ToString;
+
+    use crate::datatypes::{Char16, Handle, InvalidTimeError, RawHandle, RawTime, Time};
 
     #[test]
     fn cstr16_macro() {
@@ -416,4 +648,95 @@ mod tests {
         assert_eq!(k.as_slice()[3], Char16::new('l').unwrap());
         assert_eq!(k.as_slice()[4], Char16::new('o').unwrap());
     }
+
+    #[test]
+    fn null_handle() {
+        assert!(RawHandle::null().is_null());
+        assert_eq!(Handle::new(RawHandle::null()), None);
+    }
+
+    #[test]
+    fn non_null_handle() {
+        let mut value = 0u8;
+        let raw = RawHandle(core::ptr::from_mut(&mut value).cast::<core::ffi::c_void>());
+
+        let handle = Handle::new(raw).unwrap();
+        assert_eq!(handle.as_raw(), raw);
+        assert_eq!(handle.as_ptr(), raw.as_ptr());
+    }
+
+    /// Returns a valid [`RawTime`] for use as a base in tests.
+    fn raw_time() -> RawTime {
+        RawTime {
+            year: 2024,
+            month: 5,
+            day: 30,
+            hour: 9,
+            minute: 4,
+            second: 7,
+            time_zone: Time::UNSPECIFIED_TIMEZONE,
+            ..RawTime::default()
+        }
+    }
+
+    #[test]
+    fn time_display() {
+        let time = Time::new(raw_time()).unwrap();
+
+        assert_eq!(time.to_string(), "2024-05-30 09:04:07");
+        assert_eq!(time.time_zone(), None);
+    }
+
+    #[test]
+    fn time_rejects_invalid_month() {
+        let raw = RawTime {
+            month: 13,
+            ..raw_time()
+        };
+
+        assert_eq!(Time::new(raw), Err(InvalidTimeError::Month));
+    }
+
+    #[test]
+    fn time_leap_day() {
+        let leap = RawTime {
+            month: 2,
+            day: 29,
+            ..raw_time()
+        };
+        assert!(Time::new(leap).is_ok());
+
+        let non_leap = RawTime { year: 2023, ..leap };
+        assert_eq!(Time::new(non_leap), Err(InvalidTimeError::Day));
+    }
+
+    #[test]
+    fn time_daylight_bits() {
+        let daylight = RawTime {
+            daylight: Time::ADJUST_DAYLIGHT | Time::IN_DAYLIGHT,
+            ..raw_time()
+        };
+        assert!(Time::new(daylight).is_ok());
+
+        let invalid = RawTime {
+            daylight: 0x04,
+            ..raw_time()
+        };
+        assert_eq!(Time::new(invalid), Err(InvalidTimeError::Daylight));
+    }
+
+    #[test]
+    fn time_rejects_nonzero_padding() {
+        let pad1 = RawTime {
+            _pad1: 1,
+            ..raw_time()
+        };
+        assert_eq!(Time::new(pad1), Err(InvalidTimeError::Pad1));
+
+        let pad2 = RawTime {
+            _pad2: 1,
+            ..raw_time()
+        };
+        assert_eq!(Time::new(pad2), Err(InvalidTimeError::Pad2));
+    }
 }
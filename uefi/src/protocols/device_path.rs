@@ -0,0 +1,33 @@
+//! Protocols used to describe the location of devices and files.
+
+use crate::datatypes::Guid;
+
+/// The header of a single node of a device path.
+///
+/// A device path is a packed sequence of variable-length nodes, each beginning with a
+/// [`DevicePathProtocol`], and terminated by an end-of-path node.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(C)]
+pub struct DevicePathProtocol {
+    /// The type of the device path node.
+    pub kind: u8,
+    /// The sub-type of the device path node, whose meaning depends on [`DevicePathProtocol::kind`].
+    pub sub_kind: u8,
+    /// The length, in little-endian bytes, of the device path node, including this header.
+    pub length: [u8; 2],
+}
+
+impl DevicePathProtocol {
+    /// The [`Guid`] associated with the [`DevicePathProtocol`].
+    pub const GUID: Guid = Guid {
+        data1: 0x09576E91,
+        data2: 0x6D3F,
+        data3: 0x11D2,
+        data4: [0x8E, 0x39, 0x00, 0xA0, 0xC9, 0x69, 0x72, 0x3B],
+    };
+
+    /// Returns the length, in bytes, of the device path node, including the header.
+    pub const fn length(&self) -> u16 {
+        u16::from_le_bytes(self.length)
+    }
+}
@@ -0,0 +1,209 @@
+//! Protocols used to access files on a file system.
+
+use core::ffi::c_void;
+
+use crate::datatypes::{Char16, Guid, RawTime, Status};
+
+/// A protocol that provides access to a file system volume.
+#[repr(C)]
+pub struct SimpleFileSystemProtocol {
+    /// The revision of the [`SimpleFileSystemProtocol`].
+    pub revision: u64,
+    /// Opens the root directory of the volume, returning a [`FileProtocol`] in `root`.
+    pub open_volume:
+        unsafe extern "efiapi" fn(this: *mut Self, root: *mut *mut FileProtocol) -> Status,
+}
+
+impl SimpleFileSystemProtocol {
+    /// The [`Guid`] associated with the [`SimpleFileSystemProtocol`].
+    pub const GUID: Guid = Guid {
+        data1: 0x964E5B22,
+        data2: 0x6459,
+        data3: 0x11D2,
+        data4: [0x8E, 0x39, 0x00, 0xA0, 0xC9, 0x69, 0x72, 0x3B],
+    };
+
+    /// The revision of the [`SimpleFileSystemProtocol`] defined by the UEFI specification.
+    pub const REVISION: u64 = 0x0001_0000;
+}
+
+/// A protocol that provides access to a file or directory.
+///
+/// Only the members present in [`FileProtocol::REVISION`] are defined.
+#[repr(C)]
+pub struct FileProtocol {
+    /// The revision of the [`FileProtocol`].
+    pub revision: u64,
+    /// Opens the file named `file_name`, relative to the directory represented by `this`, and
+    /// returns its [`FileProtocol`] in `new_handle`.
+    pub open: unsafe extern "efiapi" fn(
+        this: *mut Self,
+        new_handle: *mut *mut FileProtocol,
+        file_name: *const Char16,
+        open_mode: FileMode,
+        attributes: FileAttribute,
+    ) -> Status,
+    /// Closes the file represented by `this`.
+    pub close: unsafe extern "efiapi" fn(this: *mut Self) -> Status,
+    /// Closes and deletes the file represented by `this`.
+    pub delete: unsafe extern "efiapi" fn(this: *mut Self) -> Status,
+    /// Reads up to `buffer_size` bytes from the current position of the file into `buffer`,
+    /// returning the number of bytes read in `buffer_size`.
+    ///
+    /// If `this` is a directory, a single [`FileInfo`] entry is read instead.
+    pub read: unsafe extern "efiapi" fn(
+        this: *mut Self,
+        buffer_size: *mut usize,
+        buffer: *mut c_void,
+    ) -> Status,
+    /// Writes `buffer_size` bytes from `buffer` to the current position of the file,
+    /// returning the number of bytes written in `buffer_size`.
+    pub write: unsafe extern "efiapi" fn(
+        this: *mut Self,
+        buffer_size: *mut usize,
+        buffer: *const c_void,
+    ) -> Status,
+    /// Returns the current position of the file in `position`.
+    pub get_position: unsafe extern "efiapi" fn(this: *mut Self, position: *mut u64) -> Status,
+    /// Sets the current position of the file to `position`.
+    ///
+    /// A `position` of [`u64::MAX`] sets the position to the end of the file.
+    pub set_position: unsafe extern "efiapi" fn(this: *mut Self, position: u64) -> Status,
+    /// Writes the information of type `information_type` about the file into `buffer`.
+    ///
+    /// If `buffer_size` is too small, [`Status::BUFFER_TOO_SMALL`] is returned and `buffer_size`
+    /// is updated to the required size.
+    pub get_info: unsafe extern "efiapi" fn(
+        this: *mut Self,
+        information_type: *const Guid,
+        buffer_size: *mut usize,
+        buffer: *mut c_void,
+    ) -> Status,
+    /// Sets the information of type `information_type` about the file from `buffer`.
+    pub set_info: unsafe extern "efiapi" fn(
+        this: *mut Self,
+        information_type: *const Guid,
+        buffer_size: usize,
+        buffer: *const c_void,
+    ) -> Status,
+    /// Flushes all modified data associated with the file to the device.
+    pub flush: unsafe extern "efiapi" fn(this: *mut Self) -> Status,
+}
+
+impl FileProtocol {
+    /// The first revision of the [`FileProtocol`].
+    pub const REVISION: u64 = 0x0001_0000;
+    /// The second revision of the [`FileProtocol`], which adds asynchronous operations.
+    pub const REVISION2: u64 = 0x0002_0000;
+}
+
+/// The mode in which a file is opened by [`FileProtocol::open`].
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct FileMode(pub u64);
+
+impl FileMode {
+    /// Open the file for reading.
+    pub const READ: FileMode = FileMode(0x0000_0000_0000_0001);
+    /// Open the file for reading and writing.
+    pub const READ_WRITE: FileMode = FileMode(0x0000_0000_0000_0003);
+    /// Create the file if it does not exist, and open it for reading and writing.
+    pub const CREATE_READ_WRITE: FileMode = FileMode(0x8000_0000_0000_0003);
+}
+
+/// The attributes of a file.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct FileAttribute(pub u64);
+
+impl FileAttribute {
+    /// No attributes.
+    pub const NONE: FileAttribute = FileAttribute(0x00);
+    /// The file may not be written to.
+    pub const READ_ONLY: FileAttribute = FileAttribute(0x01);
+    /// The file is hidden.
+    pub const HIDDEN: FileAttribute = FileAttribute(0x02);
+    /// The file is a system file.
+    pub const SYSTEM: FileAttribute = FileAttribute(0x04);
+    /// Reserved.
+    pub const RESERVED: FileAttribute = FileAttribute(0x08);
+    /// The file is a directory.
+    pub const DIRECTORY: FileAttribute = FileAttribute(0x10);
+    /// The file has been modified since it was last archived.
+    pub const ARCHIVE: FileAttribute = FileAttribute(0x20);
+    /// All valid attribute bits.
+    pub const VALID: FileAttribute = FileAttribute(0x37);
+
+    /// Returns `true` if all of the bits set in `other` are set in `self`.
+    pub const fn contains(self, other: FileAttribute) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Generic information about a file, returned by [`FileProtocol::get_info`].
+///
+/// In memory, a [`FileInfo`] is immediately followed by the nul-terminated name of the file,
+/// which is included in [`FileInfo::size`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[repr(C)]
+pub struct FileInfo {
+    /// The size, in bytes, of the [`FileInfo`] including the file name.
+    pub size: u64,
+    /// The size, in bytes, of the file.
+    pub file_size: u64,
+    /// The amount of physical space, in bytes, the file consumes on the volume.
+    pub physical_size: u64,
+    /// The time at which the file was created.
+    pub create_time: RawTime,
+    /// The time at which the file was last accessed.
+    pub last_access_time: RawTime,
+    /// The time at which the file was last modified.
+    pub modification_time: RawTime,
+    /// The [`FileAttribute`]s of the file.
+    pub attribute: FileAttribute,
+}
+
+impl FileInfo {
+    /// The [`Guid`] used to request a [`FileInfo`] from [`FileProtocol::get_info`].
+    pub const GUID: Guid = Guid {
+        data1: 0x09576E92,
+        data2: 0x6D3F,
+        data3: 0x11D2,
+        data4: [0x8E, 0x39, 0x00, 0xA0, 0xC9, 0x69, 0x72, 0x3B],
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{offset_of, size_of};
+
+    use crate::protocols::file::{FileAttribute, FileInfo, FileProtocol};
+
+    #[test]
+    fn file_info_layout() {
+        assert_eq!(offset_of!(FileInfo, create_time), 24);
+        assert_eq!(offset_of!(FileInfo, last_access_time), 40);
+        assert_eq!(offset_of!(FileInfo, modification_time), 56);
+        assert_eq!(offset_of!(FileInfo, attribute), 72);
+        assert_eq!(size_of::<FileInfo>(), 80);
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn file_protocol_layout() {
+        assert_eq!(offset_of!(FileProtocol, open), 8);
+        assert_eq!(offset_of!(FileProtocol, read), 32);
+        assert_eq!(offset_of!(FileProtocol, get_info), 64);
+        assert_eq!(offset_of!(FileProtocol, flush), 80);
+        assert_eq!(size_of::<FileProtocol>(), 88);
+    }
+
+    #[test]
+    fn attribute_contains() {
+        let attribute = FileAttribute(FileAttribute::DIRECTORY.0 | FileAttribute::READ_ONLY.0);
+
+        assert!(attribute.contains(FileAttribute::DIRECTORY));
+        assert!(!attribute.contains(FileAttribute::HIDDEN));
+        assert!(FileAttribute::VALID.contains(attribute));
+    }
+}
@@ -0,0 +1,79 @@
+//! Protocol used to obtain information about a loaded image.
+
+use crate::{
+    datatypes::{Guid, Handle, RawHandle, Status},
+    protocols::device_path::DevicePathProtocol,
+    tables::{boot_services::MemoryType, system::RawSystemTable},
+};
+
+/// A protocol that can be used on any image handle to obtain information about the loaded image.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct LoadedImageProtocol {
+    /// The revision of the [`LoadedImageProtocol`] structure.
+    pub revision: u32,
+    /// The handle of the image that loaded this image, or null if it was loaded by the firmware.
+    pub parent_handle: RawHandle,
+    /// The image's [`RawSystemTable`].
+    pub system_table: *mut RawSystemTable,
+
+    /// The handle of the device from which the image was loaded.
+    pub device_handle: RawHandle,
+    /// The file path portion of the device path of the image, relative to
+    /// [`LoadedImageProtocol::device_handle`].
+    pub file_path: *mut DevicePathProtocol,
+    /// Reserved, must be null.
+    pub _reserved: *mut core::ffi::c_void,
+
+    /// The size, in bytes, of [`LoadedImageProtocol::load_options`].
+    pub load_options_size: u32,
+    /// A pointer to the image's binary load options.
+    pub load_options: *mut core::ffi::c_void,
+
+    /// The base address at which the image was loaded.
+    pub image_base: *mut core::ffi::c_void,
+    /// The size, in bytes, of the loaded image.
+    pub image_size: u64,
+    /// The [`MemoryType`] that the code sections were loaded as.
+    pub image_code_type: MemoryType,
+    /// The [`MemoryType`] that the data sections were loaded as.
+    pub image_data_type: MemoryType,
+    /// Function that unloads the image.
+    pub unload: Option<unsafe extern "efiapi" fn(image_handle: Handle) -> Status>,
+}
+
+impl LoadedImageProtocol {
+    /// The [`Guid`] associated with the [`LoadedImageProtocol`].
+    pub const GUID: Guid = Guid {
+        data1: 0x5B1B31A1,
+        data2: 0x9562,
+        data3: 0x11D2,
+        data4: [0x8E, 0x3F, 0x00, 0xA0, 0xC9, 0x69, 0x72, 0x3B],
+    };
+
+    /// The revision of the [`LoadedImageProtocol`] defined by the UEFI specification.
+    pub const REVISION: u32 = 0x1000;
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{offset_of, size_of};
+
+    use crate::protocols::loaded_image::LoadedImageProtocol;
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn loaded_image_protocol_layout() {
+        assert_eq!(offset_of!(LoadedImageProtocol, parent_handle), 8);
+        assert_eq!(offset_of!(LoadedImageProtocol, device_handle), 24);
+        assert_eq!(offset_of!(LoadedImageProtocol, file_path), 32);
+        assert_eq!(offset_of!(LoadedImageProtocol, load_options_size), 48);
+        assert_eq!(offset_of!(LoadedImageProtocol, load_options), 56);
+        assert_eq!(offset_of!(LoadedImageProtocol, image_base), 64);
+        assert_eq!(offset_of!(LoadedImageProtocol, image_size), 72);
+        assert_eq!(offset_of!(LoadedImageProtocol, image_code_type), 80);
+        assert_eq!(offset_of!(LoadedImageProtocol, image_data_type), 84);
+        assert_eq!(offset_of!(LoadedImageProtocol, unload), 88);
+        assert_eq!(size_of::<LoadedImageProtocol>(), 96);
+    }
+}
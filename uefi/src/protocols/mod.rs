@@ -1,3 +1,6 @@
 //! Definitions and prototypes of UEFI protocols.
 
 pub mod console;
+pub mod device_path;
+pub mod file;
+pub mod loaded_image;
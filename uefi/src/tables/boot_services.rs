@@ -48,7 +48,13 @@ pub struct RawBootServicesTable {
     /// Removes a protocol interface from a device handle.
     pub uninstall_protocol_interface: unsafe extern "efiapi" fn(),
     /// Queries a handle to determine if it supports a specified protocol.
-    pub handle_protocol: unsafe extern "efiapi" fn(),
+    ///
+    /// On success, `interface` is set to point to the interface of `protocol` installed on `handle`.
+    pub handle_protocol: unsafe extern "efiapi" fn(
+        handle: Handle,
+        protocol: *const Guid,
+        interface: *mut *mut core::ffi::c_void,
+    ) -> Status,
     /// Must be null.
     pub _reserved: *mut (),
     /// Registers an event that is to be signaled whenever an interface is installed for a
@@ -108,7 +114,13 @@ pub struct RawBootServicesTable {
     /// criteria. The return buffer is allocated automatically.
     pub locate_handle_buffer: unsafe extern "efiapi" fn(),
     /// Finds the first handle in the handle database that supports the requested protocol.
-    pub locate_protocol: unsafe extern "efiapi" fn(),
+    ///
+    /// On success, `interface` is set to point to the first interface that matches `protocol`.
+    pub locate_protocol: unsafe extern "efiapi" fn(
+        protocol: *const Guid,
+        registration: *mut core::ffi::c_void,
+        interface: *mut *mut core::ffi::c_void,
+    ) -> Status,
     /// Installs one or more protocol interfaces onto a handle.
     pub install_multiple_protocol_interface: unsafe extern "efiapi" fn(),
     /// Removes one or more protocol interfaces from a handle.
@@ -157,3 +169,88 @@ impl RawBootServicesTable {
         unsafe { Header::validate(RawBootServicesTable::SIGNATURE, ptr.cast::<Header>()) }
     }
 }
+
+/// The type of a region of memory described by a [`MemoryDescriptor`].
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct MemoryType(pub u32);
+
+impl MemoryType {
+    /// Memory that is not usable.
+    pub const RESERVED: MemoryType = MemoryType(0);
+    /// The code portions of a loaded UEFI application.
+    pub const LOADER_CODE: MemoryType = MemoryType(1);
+    /// The data portions of a loaded UEFI application and the default allocation type
+    /// used by a UEFI application to allocate pool memory.
+    pub const LOADER_DATA: MemoryType = MemoryType(2);
+    /// The code portions of a loaded UEFI boot services driver.
+    pub const BOOT_SERVICES_CODE: MemoryType = MemoryType(3);
+    /// The data portions of a loaded UEFI boot services driver, and the default allocation
+    /// type used by a UEFI boot services driver to allocate pool memory.
+    pub const BOOT_SERVICES_DATA: MemoryType = MemoryType(4);
+    /// The code portions of a loaded UEFI runtime driver.
+    pub const RUNTIME_SERVICES_CODE: MemoryType = MemoryType(5);
+    /// The data portions of a loaded UEFI runtime driver and the default allocation type
+    /// used by a UEFI runtime driver to allocate pool memory.
+    pub const RUNTIME_SERVICES_DATA: MemoryType = MemoryType(6);
+    /// Free (unallocated) memory.
+    pub const CONVENTIONAL: MemoryType = MemoryType(7);
+    /// Memory in which errors have been detected.
+    pub const UNUSABLE: MemoryType = MemoryType(8);
+    /// Memory that holds the ACPI tables.
+    pub const ACPI_RECLAIM: MemoryType = MemoryType(9);
+    /// Address space reserved for use by the firmware.
+    pub const ACPI_NON_VOLATILE: MemoryType = MemoryType(10);
+    /// Used by system firmware to request that a memory-mapped IO region be mapped by the OS
+    /// to a virtual address so it can be accessed by UEFI runtime services.
+    pub const MMIO: MemoryType = MemoryType(11);
+    /// System memory-mapped IO region that is used to translate memory cycles to IO cycles
+    /// by the processor.
+    pub const MMIO_PORT_SPACE: MemoryType = MemoryType(12);
+    /// Address space reserved by the firmware for code that is part of the processor.
+    pub const PAL_CODE: MemoryType = MemoryType(13);
+    /// Memory that behaves like [`MemoryType::CONVENTIONAL`], but is also non-volatile.
+    pub const PERSISTENT: MemoryType = MemoryType(14);
+    /// Memory that must be accepted by the boot target before it can be used.
+    pub const UNACCEPTED: MemoryType = MemoryType(15);
+}
+
+/// Describes a single region of memory in the UEFI memory map.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(C)]
+pub struct MemoryDescriptor {
+    /// The type of the memory region.
+    pub memory_type: MemoryType,
+    /// The physical address of the first byte in the memory region.
+    ///
+    /// This must be aligned on a 4 KiB boundary.
+    pub physical_start: u64,
+    /// The virtual address of the first byte in the memory region.
+    ///
+    /// This must be aligned on a 4 KiB boundary.
+    pub virtual_start: u64,
+    /// The number of 4 KiB pages in the memory region.
+    pub number_of_pages: u64,
+    /// Attributes of the memory region that describe the bit mask of capabilities for that
+    /// memory region, and not necessarily the current settings for that memory region.
+    pub attribute: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{align_of, offset_of, size_of};
+
+    use crate::tables::boot_services::MemoryDescriptor;
+
+    #[test]
+    fn memory_descriptor_layout() {
+        assert_eq!(size_of::<MemoryDescriptor>(), 40);
+        assert_eq!(align_of::<MemoryDescriptor>(), 8);
+
+        assert_eq!(offset_of!(MemoryDescriptor, memory_type), 0);
+        assert_eq!(offset_of!(MemoryDescriptor, physical_start), 8);
+        assert_eq!(offset_of!(MemoryDescriptor, virtual_start), 16);
+        assert_eq!(offset_of!(MemoryDescriptor, number_of_pages), 24);
+        assert_eq!(offset_of!(MemoryDescriptor, attribute), 32);
+    }
+}